@@ -1563,16 +1563,19 @@ impl<T: BeaconChainTypes> BeaconChain<T> {
     ///
     /// The produced block will not be inherently valid, it must be signed by a block producer.
     /// Block signing is out of the scope of this function and should be done by a separate program.
+    ///
+    /// The block will contain `validator_graffiti`, if supplied, otherwise the default `GRAFFITI`.
     pub fn produce_block(
         &self,
         randao_reveal: Signature,
         slot: Slot,
+        validator_graffiti: Option<[u8; 32]>,
     ) -> Result<BeaconBlockAndState<T::EthSpec>, BlockProductionError> {
         let state = self
             .state_at_slot(slot - 1, StateSkipConfig::WithStateRoots)
             .map_err(|_| BlockProductionError::UnableToProduceAtSlot(slot))?;
 
-        self.produce_block_on_state(state, slot, randao_reveal)
+        self.produce_block_on_state(state, slot, randao_reveal, validator_graffiti)
     }
 
     /// Produce a block for some `slot` upon the given `state`.
@@ -1588,6 +1591,7 @@ impl<T: BeaconChainTypes> BeaconChain<T> {
         mut state: BeaconState<T::EthSpec>,
         produce_at_slot: Slot,
         randao_reveal: Signature,
+        validator_graffiti: Option<[u8; 32]>,
     ) -> Result<BeaconBlockAndState<T::EthSpec>, BlockProductionError> {
         metrics::inc_counter(&metrics::BLOCK_PRODUCTION_REQUESTS);
         let timer = metrics::start_timer(&metrics::BLOCK_PRODUCTION_TIMES);
@@ -1615,8 +1619,11 @@ impl<T: BeaconChainTypes> BeaconChain<T> {
             state.latest_block_header.canonical_root()
         };
 
-        let mut graffiti: [u8; 32] = [0; 32];
-        graffiti.copy_from_slice(GRAFFITI.as_bytes());
+        let graffiti = validator_graffiti.unwrap_or_else(|| {
+            let mut graffiti: [u8; 32] = [0; 32];
+            graffiti.copy_from_slice(GRAFFITI.as_bytes());
+            graffiti
+        });
 
         let (proposer_slashings, attester_slashings) = self.op_pool.get_slashings(&state);
 
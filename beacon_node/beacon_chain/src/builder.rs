@@ -657,16 +657,16 @@ fn genesis_block<T: EthSpec>(
     genesis_state: &mut BeaconState<T>,
     spec: &ChainSpec,
 ) -> Result<SignedBeaconBlock<T>, String> {
-    let mut genesis_block = SignedBeaconBlock {
-        message: BeaconBlock::empty(&spec),
+    let state_root = genesis_state
+        .update_tree_hash_cache()
+        .map_err(|e| format!("Error hashing genesis state: {:?}", e))?;
+
+    Ok(SignedBeaconBlock {
+        message: BeaconBlock::genesis(state_root, spec),
         // Empty signature, which should NEVER be read. This isn't to-spec, but makes the genesis
         // block consistent with every other block.
         signature: Signature::empty_signature(),
-    };
-    genesis_block.message.state_root = genesis_state
-        .update_tree_hash_cache()
-        .map_err(|e| format!("Error hashing genesis state: {:?}", e))?;
-    Ok(genesis_block)
+    })
 }
 
 #[cfg(not(debug_assertions))]
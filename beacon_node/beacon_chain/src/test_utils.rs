@@ -22,9 +22,13 @@ use store::{config::StoreConfig, HotColdDB, ItemStore, LevelDB, MemoryStore};
 use tempfile::{tempdir, TempDir};
 use tree_hash::TreeHash;
 use types::{
-    AggregateSignature, Attestation, BeaconState, BeaconStateHash, ChainSpec, Domain, EthSpec,
-    Hash256, Keypair, SecretKey, SelectionProof, Signature, SignedAggregateAndProof,
-    SignedBeaconBlock, SignedBeaconBlockHash, SignedRoot, Slot, SubnetId,
+    test_utils::{
+        AttesterSlashingTestTask, ProposerSlashingTestTask, TestingAttesterSlashingBuilder,
+        TestingProposerSlashingBuilder,
+    },
+    AggregateSignature, Attestation, AttesterSlashing, BeaconState, BeaconStateHash, ChainSpec,
+    Domain, EthSpec, Hash256, Keypair, ProposerSlashing, SecretKey, SelectionProof, Signature,
+    SignedAggregateAndProof, SignedBeaconBlock, SignedBeaconBlockHash, SignedRoot, Slot, SubnetId,
 };
 
 pub use types::test_utils::generate_deterministic_keypairs;
@@ -366,6 +370,60 @@ where
         self.chain.head().unwrap().beacon_state
     }
 
+    /// Panics if any validator which is not in `expected_slashings` is slashed in the head state.
+    ///
+    /// Useful for catching bugs which slash honest validators.
+    pub fn assert_no_unexpected_slashings(&self, expected_slashings: &[u64]) {
+        let unexpected = self
+            .get_head_state()
+            .validators
+            .iter()
+            .enumerate()
+            .filter(|(index, validator)| {
+                validator.slashed && !expected_slashings.contains(&(*index as u64))
+            })
+            .map(|(index, _)| index)
+            .collect::<Vec<_>>();
+
+        assert!(
+            unexpected.is_empty(),
+            "validators were unexpectedly slashed: {:?}",
+            unexpected
+        );
+    }
+
+    /// Returns an attester slashing in which each of `validator_indices` has signed two
+    /// conflicting attestations (a double vote).
+    pub fn make_attester_slashing(&self, validator_indices: &[u64]) -> AttesterSlashing<E> {
+        let head_info = self.chain.head_info().unwrap();
+
+        TestingAttesterSlashingBuilder::double_vote(
+            AttesterSlashingTestTask::Valid,
+            validator_indices,
+            |validator_index, message| {
+                Signature::new(message, &self.keypairs[validator_index as usize].sk)
+            },
+            &head_info.fork,
+            head_info.genesis_validators_root,
+            &self.spec,
+        )
+    }
+
+    /// Returns a proposer slashing in which `proposer_index` has signed two conflicting block
+    /// headers for the same slot.
+    pub fn make_proposer_slashing(&self, proposer_index: u64) -> ProposerSlashing {
+        let head_info = self.chain.head_info().unwrap();
+
+        TestingProposerSlashingBuilder::double_vote::<E>(
+            ProposerSlashingTestTask::Valid,
+            proposer_index,
+            &self.keypairs[proposer_index as usize].sk,
+            &head_info.fork,
+            head_info.genesis_validators_root,
+            &self.spec,
+        )
+    }
+
     /// Adds a single block (synchronously) onto either the canonical chain (block_strategy ==
     /// OnCanonicalHead) or a fork (block_strategy == ForkCanonicalChainAt).
     pub fn add_block(
@@ -517,7 +575,7 @@ where
 
         let (block, state) = self
             .chain
-            .produce_block_on_state(state, slot, randao_reveal)
+            .produce_block_on_state(state, slot, randao_reveal, None)
             .expect("should produce block");
 
         let signed_block = block.sign(sk, &state.fork, state.genesis_validators_root, &self.spec);
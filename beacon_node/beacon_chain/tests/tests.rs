@@ -5,6 +5,7 @@ extern crate lazy_static;
 
 use beacon_chain::{
     attestation_verification::Error as AttnError,
+    observed_operations::ObservationOutcome,
     test_utils::{
         AttestationStrategy, BeaconChainHarness, BlockStrategy, HarnessType, OP_POOL_DB_KEY,
     },
@@ -334,6 +335,142 @@ fn does_not_finalize_without_attestation() {
     );
 }
 
+#[test]
+fn attester_slashing_included_in_block() {
+    let harness = get_harness(VALIDATOR_COUNT);
+
+    harness.extend_chain(
+        MinimalEthSpec::slots_per_epoch() as usize,
+        BlockStrategy::OnCanonicalHead,
+        AttestationStrategy::AllValidators,
+    );
+
+    let slashed_indices = vec![0, 1];
+    let slashing = harness.make_attester_slashing(&slashed_indices);
+    let verified_slashing = match harness
+        .chain
+        .verify_attester_slashing_for_gossip(slashing)
+        .expect("slashing should be valid")
+    {
+        ObservationOutcome::New(verified_slashing) => verified_slashing,
+        ObservationOutcome::AlreadyKnown => panic!("slashing should be new"),
+    };
+    harness
+        .chain
+        .import_attester_slashing(verified_slashing)
+        .expect("should import slashing");
+
+    harness.extend_chain(
+        1,
+        BlockStrategy::OnCanonicalHead,
+        AttestationStrategy::AllValidators,
+    );
+
+    let head = harness.chain.head().expect("should get head");
+    assert_eq!(head.beacon_block.message.body.attester_slashings.len(), 1);
+
+    for (index, validator) in head.beacon_state.validators.iter().enumerate() {
+        assert_eq!(
+            validator.slashed,
+            slashed_indices.contains(&(index as u64)),
+            "validator {} should only be slashed if it was in the slashing",
+            index
+        );
+    }
+}
+
+#[test]
+fn no_unexpected_slashings_on_clean_chain() {
+    let harness = get_harness(VALIDATOR_COUNT);
+
+    harness.extend_chain(
+        MinimalEthSpec::slots_per_epoch() as usize * 2,
+        BlockStrategy::OnCanonicalHead,
+        AttestationStrategy::AllValidators,
+    );
+
+    harness.assert_no_unexpected_slashings(&[]);
+}
+
+#[test]
+#[should_panic(expected = "validators were unexpectedly slashed: [2]")]
+fn detects_unexpected_slashing() {
+    let harness = get_harness(VALIDATOR_COUNT);
+
+    harness.extend_chain(
+        MinimalEthSpec::slots_per_epoch() as usize,
+        BlockStrategy::OnCanonicalHead,
+        AttestationStrategy::AllValidators,
+    );
+
+    // Slash validators 1 and 2, but only declare validator 1.
+    let slashing = harness.make_attester_slashing(&[1, 2]);
+    if let ObservationOutcome::New(verified_slashing) = harness
+        .chain
+        .verify_attester_slashing_for_gossip(slashing)
+        .expect("slashing should be valid")
+    {
+        harness
+            .chain
+            .import_attester_slashing(verified_slashing)
+            .expect("should import slashing");
+    }
+
+    harness.extend_chain(
+        1,
+        BlockStrategy::OnCanonicalHead,
+        AttestationStrategy::AllValidators,
+    );
+
+    harness.assert_no_unexpected_slashings(&[1]);
+}
+
+#[test]
+fn proposer_slashing_included_in_block() {
+    let harness = get_harness(VALIDATOR_COUNT);
+
+    harness.extend_chain(
+        MinimalEthSpec::slots_per_epoch() as usize,
+        BlockStrategy::OnCanonicalHead,
+        AttestationStrategy::AllValidators,
+    );
+
+    let slashed_index = 3;
+    let balance_before = harness.get_head_state().balances[slashed_index];
+
+    let slashing = harness.make_proposer_slashing(slashed_index as u64);
+    let verified_slashing = match harness
+        .chain
+        .verify_proposer_slashing_for_gossip(slashing)
+        .expect("slashing should be valid")
+    {
+        ObservationOutcome::New(verified_slashing) => verified_slashing,
+        ObservationOutcome::AlreadyKnown => panic!("slashing should be new"),
+    };
+    harness.chain.import_proposer_slashing(verified_slashing);
+
+    harness.extend_chain(
+        1,
+        BlockStrategy::OnCanonicalHead,
+        AttestationStrategy::AllValidators,
+    );
+
+    let head = harness.chain.head().expect("should get head");
+    assert_eq!(head.beacon_block.message.body.proposer_slashings.len(), 1);
+
+    let state = &head.beacon_state;
+    assert!(state.validators[slashed_index].slashed);
+    assert!(
+        state.balances[slashed_index] < balance_before,
+        "slashed validator should be penalized"
+    );
+    assert_eq!(
+        state.validators.iter().filter(|v| v.slashed).count(),
+        1,
+        "only the proposer should be slashed"
+    );
+}
+
 #[test]
 fn roundtrip_operation_pool() {
     let num_blocks_produced = MinimalEthSpec::slots_per_epoch() * 5;
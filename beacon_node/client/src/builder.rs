@@ -331,6 +331,7 @@ where
         Client {
             beacon_chain: self.beacon_chain,
             network_globals: self.network_globals,
+            network_send: self.network_send,
             http_listen_addr: self.http_listen_addr,
             websocket_listen_addr: self.websocket_listen_addr,
         }
@@ -8,9 +8,12 @@ pub mod builder;
 pub mod error;
 
 use beacon_chain::BeaconChain;
-use eth2_libp2p::{Enr, Multiaddr, NetworkGlobals};
+use eth2_libp2p::{Enr, Multiaddr, NetworkGlobals, PeerId};
+use network::NetworkMessage;
 use std::net::SocketAddr;
 use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::mpsc::UnboundedSender;
 
 pub use beacon_chain::{BeaconChainTypes, Eth1ChainBackend};
 pub use builder::ClientBuilder;
@@ -23,6 +26,7 @@ pub use eth2_config::Eth2Config;
 pub struct Client<T: BeaconChainTypes> {
     beacon_chain: Option<Arc<BeaconChain<T>>>,
     network_globals: Option<Arc<NetworkGlobals<T::EthSpec>>>,
+    network_send: Option<UnboundedSender<NetworkMessage<T::EthSpec>>>,
     http_listen_addr: Option<SocketAddr>,
     websocket_listen_addr: Option<SocketAddr>,
 }
@@ -57,4 +61,18 @@ impl<T: BeaconChainTypes> Client<T> {
     pub fn enr(&self) -> Option<Enr> {
         self.network_globals.as_ref().map(|n| n.local_enr())
     }
+
+    /// Returns the libp2p `PeerId` of this node, if the network was started.
+    pub fn peer_id(&self) -> Option<PeerId> {
+        self.network_globals.as_ref().map(|n| n.local_peer_id())
+    }
+
+    /// Disconnects from `peer_id` and refuses connections from it for `duration`.
+    pub fn ban_peer(&self, peer_id: PeerId, duration: Duration) -> Result<(), String> {
+        self.network_send
+            .as_ref()
+            .ok_or_else(|| "The network was not started".to_string())?
+            .send(NetworkMessage::BanPeer { peer_id, duration })
+            .map_err(|e| format!("Unable to send to the network service: {:?}", e))
+    }
 }
@@ -39,6 +39,10 @@ pub struct Config {
     /// Target number of connected peers.
     pub max_peers: usize,
 
+    /// The number of recently verified gossip blocks and attestations to remember, so that
+    /// duplicates received from other peers are not verified again.
+    pub gossip_cache_size: usize,
+
     /// Gossipsub configuration parameters.
     #[serde(skip)]
     pub gs_config: GossipsubConfig,
@@ -53,6 +57,16 @@ pub struct Config {
     /// List of libp2p nodes to initially connect to.
     pub libp2p_nodes: Vec<Multiaddr>,
 
+    /// The delay in seconds before redialing one of the `libp2p_nodes` after its first failed
+    /// dial. The delay doubles after each subsequent failure.
+    pub dial_backoff_initial_secs: u64,
+
+    /// The maximum delay in seconds between redials of one of the `libp2p_nodes`.
+    pub dial_backoff_max_secs: u64,
+
+    /// The number of failed dials after which one of the `libp2p_nodes` is no longer redialed.
+    pub dial_max_attempts: usize,
+
     /// Client version
     pub client_version: String,
 
@@ -123,10 +137,14 @@ impl Default for Config {
             enr_udp_port: None,
             enr_tcp_port: None,
             max_peers: 50,
+            gossip_cache_size: 4_096,
             gs_config,
             discv5_config,
             boot_nodes: vec![],
             libp2p_nodes: vec![],
+            dial_backoff_initial_secs: 1,
+            dial_backoff_max_secs: 300,
+            dial_max_attempts: 10,
             client_version: version::version(),
             disable_discovery: false,
             topics,
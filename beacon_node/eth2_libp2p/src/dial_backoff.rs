@@ -0,0 +1,74 @@
+use std::time::Duration;
+
+/// Determines how long to wait before redialing a peer whose previous dials have failed.
+///
+/// The delay starts at `initial_delay` and doubles after each consecutive failure, up to
+/// `max_delay`. Once `max_attempts` dials have failed, the peer is no longer redialed.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DialBackoff {
+    initial_delay: Duration,
+    max_delay: Duration,
+    max_attempts: usize,
+}
+
+impl DialBackoff {
+    pub fn new(initial_delay: Duration, max_delay: Duration, max_attempts: usize) -> Self {
+        Self {
+            initial_delay,
+            max_delay,
+            max_attempts,
+        }
+    }
+
+    /// Returns the delay before redialing a peer which has failed `failures` consecutive dials,
+    /// or `None` if the peer should not be dialed again.
+    pub fn delay(&self, failures: usize) -> Option<Duration> {
+        if failures >= self.max_attempts {
+            return None;
+        }
+
+        // Cap the exponent to avoid overflowing the shift. Any delay this large exceeds any
+        // sensible `max_delay` anyway.
+        let exponent = failures.saturating_sub(1).min(31) as u32;
+        let delay = self
+            .initial_delay
+            .checked_mul(1 << exponent)
+            .unwrap_or(self.max_delay);
+
+        Some(std::cmp::min(delay, self.max_delay))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backoff_schedule() {
+        let backoff = DialBackoff::new(Duration::from_secs(1), Duration::from_secs(10), 6);
+
+        let delays = (1..=6)
+            .map(|failures| backoff.delay(failures))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            delays,
+            vec![
+                Some(Duration::from_secs(1)),
+                Some(Duration::from_secs(2)),
+                Some(Duration::from_secs(4)),
+                Some(Duration::from_secs(8)),
+                Some(Duration::from_secs(10)),
+                None,
+            ]
+        );
+    }
+
+    #[test]
+    fn backoff_does_not_overflow() {
+        let backoff = DialBackoff::new(Duration::from_secs(1), Duration::from_secs(300), 1_000);
+
+        assert_eq!(backoff.delay(100), Some(Duration::from_secs(300)));
+        assert_eq!(backoff.delay(1_000), None);
+    }
+}
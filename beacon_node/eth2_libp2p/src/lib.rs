@@ -7,6 +7,7 @@ extern crate lazy_static;
 
 pub mod behaviour;
 mod config;
+mod dial_backoff;
 pub mod discovery;
 mod metrics;
 mod peer_manager;
@@ -23,5 +24,5 @@ pub use libp2p::gossipsub::{MessageId, Topic, TopicHash};
 pub use libp2p::{core::ConnectedPoint, PeerId, Swarm};
 pub use libp2p::{multiaddr, Multiaddr};
 pub use metrics::scrape_discovery_metrics;
-pub use peer_manager::{client::Client, PeerDB, PeerInfo, PeerSyncStatus, SyncInfo};
+pub use peer_manager::{client::Client, PeerAction, PeerDB, PeerInfo, PeerSyncStatus, SyncInfo};
 pub use service::{Libp2pEvent, Service, NETWORK_KEY_FILENAME};
@@ -17,6 +17,18 @@ lazy_static! {
         "libp2p_peer_disconnect_event_total",
         "Count of libp2p peer disconnect events"
     );
+    pub static ref PEER_REPUTATION_DISCONNECT_COUNT: Result<IntCounter> = try_create_int_counter(
+        "libp2p_peer_reputation_disconnect_total",
+        "Count of peers disconnected because their reputation fell below the threshold"
+    );
+    pub static ref PEER_REPUTATION_MIN: Result<IntGauge> = try_create_int_gauge(
+        "libp2p_peer_reputation_min",
+        "The lowest reputation of all connected peers"
+    );
+    pub static ref PEER_REPUTATION_MEAN: Result<Gauge> = try_create_float_gauge(
+        "libp2p_peer_reputation_mean",
+        "The mean reputation of all connected peers"
+    );
     pub static ref DISCOVERY_QUEUE: Result<IntGauge> = try_create_int_gauge(
         "discovery_queue_size",
         "The number of discovery queries awaiting execution"
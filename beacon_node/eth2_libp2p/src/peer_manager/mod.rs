@@ -30,9 +30,6 @@ mod peerdb;
 
 pub use peer_info::{PeerConnectionStatus::*, PeerInfo};
 pub use peer_sync_status::{PeerSyncStatus, SyncInfo};
-/// The minimum reputation before a peer is disconnected.
-// Most likely this needs tweaking.
-const _MIN_REP_BEFORE_BAN: Rep = 10;
 /// The time in seconds between re-status's peers.
 const STATUS_INTERVAL: u64 = 300;
 /// The time in seconds between PING events. We do not send a ping if the other peer as PING'd us within
@@ -67,6 +64,7 @@ pub struct PeerManager<TSpec: EthSpec> {
 /// Each variant has an associated reputation change.
 // To easily assess the behaviour of reputation changes the number of variants should stay low, and
 // somewhat generic.
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum PeerAction {
     /// We should not communicate more with this peer.
     /// This action will cause the peer to get banned.
@@ -86,7 +84,7 @@ pub enum PeerAction {
     /// NOTE: ~5 occurrences will get the peer banned
     LowToleranceError,
     /// Received an expected message.
-    _ValidMessage,
+    ValidMessage,
 }
 
 impl PeerAction {
@@ -96,7 +94,7 @@ impl PeerAction {
             PeerAction::LowToleranceError => RepChange::bad(60),
             PeerAction::MidToleranceError => RepChange::bad(25),
             PeerAction::HighToleranceError => RepChange::bad(15),
-            PeerAction::_ValidMessage => RepChange::good(20),
+            PeerAction::ValidMessage => RepChange::good(20),
         }
     }
 }
@@ -219,13 +217,21 @@ impl<TSpec: EthSpec> PeerManager<TSpec> {
     /// Reports a peer for some action.
     ///
     /// If the peer doesn't exist, log a warning and insert defaults.
+    /// Peers whose reputation drops below `MIN_REP_BEFORE_BAN` are marked for disconnection.
     pub fn report_peer(&mut self, peer_id: &PeerId, action: PeerAction) {
         //TODO: Check these. There are double disconnects for example
         // self.update_reputations();
-        self.network_globals
-            .peers
-            .write()
-            .add_reputation(peer_id, action.rep_change());
+        let mut peerdb = self.network_globals.peers.write();
+        let was_below_threshold = peerdb.below_reputation_threshold(peer_id);
+        peerdb.add_reputation(peer_id, action.rep_change());
+
+        if !was_below_threshold && peerdb.below_reputation_threshold(peer_id) {
+            debug!(self.log, "Peer reputation below threshold, disconnecting";
+                "peer_id" => peer_id.to_string(), "reputation" => peerdb.reputation(peer_id));
+            metrics::inc_counter(&metrics::PEER_REPUTATION_DISCONNECT_COUNT);
+            self.events
+                .push(PeerManagerEvent::DisconnectPeer(peer_id.clone()));
+        }
         // self.update_reputations();
     }
 
@@ -563,10 +569,10 @@ impl<TSpec: EthSpec> PeerManager<TSpec> {
                 Unknown => {} //TODO: Handle this case
             }
             // Check if the peer gets banned or unbanned and if it should be disconnected
-            if info.reputation < _MIN_REP_BEFORE_BAN && !info.connection_status.is_banned() {
+            if info.reputation < MIN_REP_BEFORE_BAN && !info.connection_status.is_banned() {
                 // This peer gets banned. Check if we should request disconnection
                 ban_queue.push(id.clone());
-            } else if info.reputation >= _MIN_REP_BEFORE_BAN && info.connection_status.is_banned() {
+            } else if info.reputation >= MIN_REP_BEFORE_BAN && info.connection_status.is_banned() {
                 // This peer gets unbanned
                 unban_queue.push(id.clone());
             }
@@ -606,6 +612,27 @@ impl<TSpec: EthSpec> PeerManager<TSpec> {
         // validation.
 
         // TODO: Perform peer reputation maintenance here
+
+        self.update_reputation_metrics();
+    }
+
+    /// Sets the aggregate reputation metrics from the reputations of all connected peers.
+    fn update_reputation_metrics(&self) {
+        let peerdb = self.network_globals.peers.read();
+        let reputations = peerdb
+            .connected_peers()
+            .map(|(_, info)| u64::from(info.reputation))
+            .collect::<Vec<_>>();
+
+        let min = reputations.iter().min().copied().unwrap_or(0);
+        let mean = if reputations.is_empty() {
+            0.0
+        } else {
+            reputations.iter().sum::<u64>() as f64 / reputations.len() as f64
+        };
+
+        metrics::set_gauge(&metrics::PEER_REPUTATION_MIN, min as i64);
+        metrics::set_float_gauge(&metrics::PEER_REPUTATION_MEAN, mean);
     }
 }
 
@@ -673,3 +700,89 @@ enum ConnectingType {
     /// We have successfully dialed a peer.
     OutgoingConnected,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::discovery::{build_enr, CombinedKey, CombinedKeyExt};
+    use types::{EnrForkId, MinimalEthSpec};
+
+    type E = MinimalEthSpec;
+
+    /// Builds a `PeerManager` that does not start discovery.
+    fn build_peer_manager() -> PeerManager<E> {
+        let keypair = Keypair::generate_secp256k1();
+        let config = NetworkConfig {
+            disable_discovery: true,
+            ..NetworkConfig::default()
+        };
+        let enr_key = CombinedKey::from_libp2p(&keypair).unwrap();
+        let enr = build_enr::<E>(&enr_key, &config, EnrForkId::default()).unwrap();
+        let log = slog::Logger::root(slog::Discard, slog::o!());
+        let network_globals = Arc::new(NetworkGlobals::new(
+            enr,
+            config.libp2p_port,
+            config.discovery_port,
+            &log,
+        ));
+
+        PeerManager::new(&keypair, &config, network_globals, &log).unwrap()
+    }
+
+    /// Returns the peers that the `peer_manager` has requested to disconnect.
+    fn disconnected_peers(peer_manager: &PeerManager<E>) -> Vec<PeerId> {
+        peer_manager
+            .events
+            .iter()
+            .filter_map(|event| match event {
+                PeerManagerEvent::DisconnectPeer(peer_id) => Some(peer_id.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn report_peer_below_threshold_disconnects() {
+        let mut peer_manager = build_peer_manager();
+        let peer_id = PeerId::random();
+        peer_manager
+            .network_globals
+            .peers
+            .write()
+            .connect_ingoing(&peer_id);
+
+        peer_manager.report_peer(&peer_id, PeerAction::MidToleranceError);
+        assert!(disconnected_peers(&peer_manager).is_empty());
+
+        peer_manager.report_peer(&peer_id, PeerAction::MidToleranceError);
+        assert_eq!(disconnected_peers(&peer_manager), vec![peer_id.clone()]);
+
+        // The peer is only disconnected once, when it first drops below the threshold.
+        peer_manager.report_peer(&peer_id, PeerAction::MidToleranceError);
+        assert_eq!(disconnected_peers(&peer_manager), vec![peer_id]);
+    }
+
+    #[tokio::test]
+    async fn single_invalid_gossip_message_does_not_disconnect() {
+        // The penalties applied to peers that gossip an invalid block or attestation.
+        for action in &[
+            PeerAction::MidToleranceError,
+            PeerAction::HighToleranceError,
+        ] {
+            let mut peer_manager = build_peer_manager();
+            let peer_id = PeerId::random();
+            peer_manager
+                .network_globals
+                .peers
+                .write()
+                .connect_ingoing(&peer_id);
+
+            peer_manager.report_peer(&peer_id, *action);
+            assert!(
+                disconnected_peers(&peer_manager).is_empty(),
+                "{:?} disconnected a peer with the default reputation",
+                action
+            );
+        }
+    }
+}
@@ -22,6 +22,10 @@ const MAX_DC_PEERS: usize = 30;
 /// The default starting reputation for an unknown peer.
 pub const DEFAULT_REPUTATION: Rep = 50;
 
+/// The minimum reputation before a peer is disconnected.
+// Most likely this needs tweaking.
+pub const MIN_REP_BEFORE_BAN: Rep = 10;
+
 /// Storage of known peers, their reputation and information
 pub struct PeerDB<TSpec: EthSpec> {
     /// The collection of known connected peers, their status and reputation
@@ -220,6 +224,12 @@ impl<TSpec: EthSpec> PeerDB<TSpec> {
         }
     }
 
+    /// Returns true if the peer is connected and its reputation has dropped below
+    /// `MIN_REP_BEFORE_BAN`, meaning it should be disconnected.
+    pub fn below_reputation_threshold(&self, peer_id: &PeerId) -> bool {
+        self.is_connected(peer_id) && self.reputation(peer_id) < MIN_REP_BEFORE_BAN
+    }
+
     /// If we are connected or currently dialing the peer returns true.
     pub fn is_connected_or_dialing(&self, peer_id: &PeerId) -> bool {
         match self.connection_status(peer_id) {
@@ -578,4 +588,27 @@ mod tests {
         pdb.disconnect(&random_peer);
         assert_eq!(pdb.n_dc, pdb.disconnected_peers().count());
     }
+
+    #[test]
+    fn test_reputation_below_threshold() {
+        use crate::peer_manager::PeerAction;
+
+        let mut pdb = get_db();
+        let random_peer = PeerId::random();
+
+        pdb.connect_ingoing(&random_peer);
+        assert!(!pdb.below_reputation_threshold(&random_peer));
+
+        pdb.add_reputation(&random_peer, PeerAction::MidToleranceError.rep_change());
+        assert!(pdb.reputation(&random_peer) >= MIN_REP_BEFORE_BAN);
+        assert!(!pdb.below_reputation_threshold(&random_peer));
+
+        pdb.add_reputation(&random_peer, PeerAction::MidToleranceError.rep_change());
+        assert!(pdb.reputation(&random_peer) < MIN_REP_BEFORE_BAN);
+        assert!(pdb.below_reputation_threshold(&random_peer));
+
+        // Disconnected peers are not marked for disconnection again.
+        pdb.disconnect(&random_peer);
+        assert!(!pdb.below_reputation_threshold(&random_peer));
+    }
 }
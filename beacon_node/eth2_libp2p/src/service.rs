@@ -1,4 +1,5 @@
 use crate::behaviour::{Behaviour, BehaviourEvent, PeerRequestId, Request, Response};
+use crate::dial_backoff::DialBackoff;
 use crate::discovery::enr;
 use crate::multiaddr::Protocol;
 use crate::rpc::{RPCResponseErrorCode, RequestId};
@@ -20,6 +21,7 @@ use libp2p::{
     PeerId, Swarm, Transport,
 };
 use slog::{crit, debug, info, o, trace, warn};
+use std::collections::HashMap;
 use std::fs::File;
 use std::io::prelude::*;
 use std::io::{Error, ErrorKind};
@@ -79,6 +81,18 @@ pub struct Service<TSpec: EthSpec> {
     /// A list of timeouts after which peers become unbanned.
     peer_ban_timeout: DelayQueue<PeerId>,
 
+    /// The user-specified libp2p addresses, which are redialed if they cannot be reached.
+    manual_peers: Vec<Multiaddr>,
+
+    /// The number of consecutive failed dials to each of the `manual_peers`.
+    dial_failures: HashMap<Multiaddr, usize>,
+
+    /// The `manual_peers` awaiting a redial after a failed dial.
+    redial_queue: DelayQueue<Multiaddr>,
+
+    /// Determines the delay before redialing one of the `manual_peers`.
+    dial_backoff: DialBackoff,
+
     /// The libp2p logger handle.
     pub log: slog::Logger,
 }
@@ -215,6 +229,14 @@ impl<TSpec: EthSpec> Service<TSpec> {
             network_globals: network_globals.clone(),
             peers_to_ban: DelayQueue::new(),
             peer_ban_timeout: DelayQueue::new(),
+            manual_peers: config.libp2p_nodes.clone(),
+            dial_failures: HashMap::new(),
+            redial_queue: DelayQueue::new(),
+            dial_backoff: DialBackoff::new(
+                Duration::from_secs(config.dial_backoff_initial_secs),
+                Duration::from_secs(config.dial_backoff_max_secs),
+                config.dial_max_attempts,
+            ),
             log,
         };
 
@@ -231,6 +253,50 @@ impl<TSpec: EthSpec> Service<TSpec> {
         self.peer_ban_timeout.insert(peer_id, timeout);
     }
 
+    /// Returns the user-specified libp2p address that `address` was dialed from, if any.
+    ///
+    /// The address reported by libp2p may not include the `/p2p` component of the user-specified
+    /// address.
+    fn manual_peer(&self, address: &Multiaddr) -> Option<Multiaddr> {
+        self.manual_peers
+            .iter()
+            .find(|manual| {
+                *manual == address
+                    || manual
+                        .iter()
+                        .filter(|protocol| !matches!(protocol, Protocol::P2p(_)))
+                        .collect::<Multiaddr>()
+                        == *address
+            })
+            .cloned()
+    }
+
+    /// Schedules a redial of the user-specified libp2p address that `address` was dialed from,
+    /// unless it has failed too many times.
+    fn on_dial_failure(&mut self, address: &Multiaddr) {
+        if let Some(manual) = self.manual_peer(address) {
+            let failures = self.dial_failures.entry(manual.clone()).or_insert(0);
+            *failures += 1;
+
+            match self.dial_backoff.delay(*failures) {
+                Some(delay) => {
+                    debug!(self.log, "Scheduling libp2p peer redial"; "address" => manual.to_string(), "failures" => *failures, "delay" => format!("{:?}", delay));
+                    self.redial_queue.insert(manual, delay);
+                }
+                None => {
+                    warn!(self.log, "Unable to reach libp2p peer, giving up"; "address" => manual.to_string(), "failures" => *failures);
+                }
+            }
+        }
+    }
+
+    /// Resets the backoff for the user-specified libp2p address that `address` was dialed from.
+    fn on_dial_success(&mut self, address: &Multiaddr) {
+        if let Some(manual) = self.manual_peer(address) {
+            self.dial_failures.remove(&manual);
+        }
+    }
+
     /// Sends a request to a peer, with a given Id.
     pub fn send_request(&mut self, peer_id: PeerId, request_id: RequestId, request: Request) {
         self.swarm.send_request(peer_id, request_id, request);
@@ -266,6 +332,9 @@ impl<TSpec: EthSpec> Service<TSpec> {
                             num_established,
                         } => {
                             debug!(self.log, "Connection established"; "peer_id" => peer_id.to_string(), "connections" => num_established.get());
+                            if let ConnectedPoint::Dialer { address } = &endpoint {
+                                self.on_dial_success(address);
+                            }
                             // if this is the first connection inform the network layer a new connection
                             // has been established and update the db
                             if num_established.get() == 1 {
@@ -331,9 +400,11 @@ impl<TSpec: EthSpec> Service<TSpec> {
                         } => {
                             debug!(self.log, "Failed to dial address"; "peer_id" => peer_id.to_string(), "address" => address.to_string(), "error" => error.to_string(), "attempts_remaining" => attempts_remaining);
                             self.swarm.peer_manager().notify_disconnect(&peer_id);
+                            self.on_dial_failure(&address);
                         }
                         SwarmEvent::UnknownPeerUnreachableAddr { address, error } => {
                             debug!(self.log, "Peer not known at dialed address"; "address" => address.to_string(), "error" => error.to_string());
+                            self.on_dial_failure(&address);
                         }
                         SwarmEvent::ExpiredListenAddr(multiaddr) => {
                             debug!(self.log, "Listen address expired"; "multiaddr" => multiaddr.to_string())
@@ -358,6 +429,16 @@ impl<TSpec: EthSpec> Service<TSpec> {
                     // inform the behaviour that the peer has been banned
                     self.swarm.peer_banned(peer_id);
                 }
+                Some(Ok(redial)) = self.redial_queue.next() => {
+                    let address = redial.into_inner();
+                    match Swarm::dial_addr(&mut self.swarm, address.clone()) {
+                        Ok(()) => debug!(self.log, "Redialing libp2p peer"; "address" => address.to_string()),
+                        Err(err) => {
+                            debug!(self.log, "Could not redial libp2p peer"; "address" => address.to_string(), "error" => format!("{:?}", err));
+                            self.on_dial_failure(&address);
+                        }
+                    }
+                }
                 Some(Ok(peer_to_unban)) = self.peer_ban_timeout.next() => {
                     debug!(self.log, "Peer has been unbanned"; "peer" => format!("{:?}", peer_to_unban));
                     let unban_peer = peer_to_unban.into_inner();
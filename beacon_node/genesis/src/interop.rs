@@ -14,9 +14,33 @@ pub fn interop_genesis_state<T: EthSpec>(
     genesis_time: u64,
     spec: &ChainSpec,
 ) -> Result<BeaconState<T>, String> {
+    let amounts = vec![spec.max_effective_balance; keypairs.len()];
+    interop_genesis_state_with_amounts(keypairs, &amounts, genesis_time, spec)
+}
+
+/// Builds an interop genesis state (see `interop_genesis_state`) in which the validator with
+/// keypair `keypairs[i]` deposits `amounts[i]` gwei, instead of the max effective balance.
+///
+/// Validators which deposit less than the max effective balance are not active at genesis.
+///
+/// Returns an error if any amount is not a valid deposit amount (see
+/// `DepositData::is_valid_amount`).
+pub fn interop_genesis_state_with_amounts<T: EthSpec>(
+    keypairs: &[Keypair],
+    amounts: &[u64],
+    genesis_time: u64,
+    spec: &ChainSpec,
+) -> Result<BeaconState<T>, String> {
+    if keypairs.len() != amounts.len() {
+        return Err(format!(
+            "{} deposit amounts supplied for {} validators",
+            amounts.len(),
+            keypairs.len()
+        ));
+    }
+
     let eth1_block_hash = Hash256::from_slice(&[0x42; 32]);
     let eth1_timestamp = 2_u64.pow(40);
-    let amount = spec.max_effective_balance;
 
     let withdrawal_credentials = |pubkey: &PublicKey| {
         let mut credentials = hash(&pubkey.as_ssz_bytes());
@@ -26,7 +50,8 @@ pub fn interop_genesis_state<T: EthSpec>(
 
     let datas = keypairs
         .into_par_iter()
-        .map(|keypair| {
+        .zip(amounts.into_par_iter())
+        .map(|(keypair, &amount)| {
             let mut data = DepositData {
                 withdrawal_credentials: withdrawal_credentials(&keypair.pk),
                 pubkey: keypair.pk.clone().into(),
@@ -40,6 +65,13 @@ pub fn interop_genesis_state<T: EthSpec>(
         })
         .collect::<Vec<_>>();
 
+    if let Some(data) = datas.iter().find(|data| !data.is_valid_amount(spec)) {
+        return Err(format!(
+            "Invalid deposit amount {} gwei: must be at least {} gwei and a multiple of {} gwei",
+            data.amount, spec.min_deposit_amount, spec.effective_balance_increment
+        ));
+    }
+
     let mut state = initialize_beacon_state_from_eth1(
         eth1_block_hash,
         eth1_timestamp,
@@ -117,4 +149,47 @@ mod test {
             "validator count should be correct"
         );
     }
+
+    #[test]
+    fn interop_state_with_amounts() {
+        let spec = &TestEthSpec::default_spec();
+        let keypairs = generate_deterministic_keypairs(4);
+        let partial = spec.max_effective_balance / 2;
+        let amounts = vec![
+            spec.max_effective_balance,
+            partial,
+            spec.max_effective_balance,
+            partial,
+        ];
+
+        let state =
+            interop_genesis_state_with_amounts::<TestEthSpec>(&keypairs, &amounts, 42, spec)
+                .expect("should build state");
+
+        assert_eq!(state.balances.to_vec(), amounts);
+        for (i, v) in state.validators.iter().enumerate() {
+            assert_eq!(
+                v.is_active_at(state.current_epoch()),
+                amounts[i] == spec.max_effective_balance,
+                "only fully-funded validators should be active at genesis"
+            );
+        }
+
+        assert!(
+            interop_genesis_state_with_amounts::<TestEthSpec>(&keypairs, &amounts[1..], 42, spec)
+                .is_err(),
+            "should reject mismatched amounts"
+        );
+
+        for invalid in &[spec.min_deposit_amount - 1, spec.max_effective_balance + 1] {
+            let mut amounts = amounts.clone();
+            amounts[1] = *invalid;
+
+            assert!(
+                interop_genesis_state_with_amounts::<TestEthSpec>(&keypairs, &amounts, 42, spec)
+                    .is_err(),
+                "should reject an invalid deposit amount"
+            );
+        }
+    }
 }
@@ -20,6 +20,10 @@ lazy_static! {
         "network_gossip_aggregated_attestations_rx_total",
         "Count of gossip aggregated attestations received"
     );
+    pub static ref GOSSIP_DUPLICATES_DROPPED: Result<IntCounter> = try_create_int_counter(
+        "network_gossip_duplicates_dropped_total",
+        "Count of gossip blocks and attestations dropped because they were recently verified"
+    );
 
     /*
      * Gossip Tx
@@ -0,0 +1,119 @@
+//! A cache of recently verified gossip messages.
+//!
+//! The same block or attestation is typically received from several peers. Once a message has
+//! passed gossip verification, later copies of it can be dropped without verifying them again.
+
+use lru::LruCache;
+use tree_hash::TreeHash;
+use types::{Attestation, EthSpec, Hash256, SignedAggregateAndProof, SignedBeaconBlock};
+
+/// Stores the roots of recently verified gossip blocks and attestations.
+///
+/// Only messages that passed verification are inserted. This stops an invalid copy of a message
+/// (e.g., one with a bad signature) from causing a valid copy with the same root to be dropped.
+pub struct GossipCache {
+    /// `None` if the cache is disabled.
+    seen: Option<LruCache<Hash256, ()>>,
+}
+
+impl GossipCache {
+    /// Creates a cache that remembers at most `capacity` messages.
+    ///
+    /// A `capacity` of zero disables the cache, so that every message is verified.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            seen: if capacity > 0 {
+                Some(LruCache::new(capacity))
+            } else {
+                None
+            },
+        }
+    }
+
+    /// Returns the key used for a gossip block. This is the block root.
+    pub fn block_key<E: EthSpec>(block: &SignedBeaconBlock<E>) -> Hash256 {
+        block.canonical_root()
+    }
+
+    /// Returns the key used for an unaggregated attestation.
+    ///
+    /// The root of the whole attestation is used, rather than the root of its `data`, since many
+    /// distinct attestations from different validators share the same `data`.
+    pub fn attestation_key<E: EthSpec>(attestation: &Attestation<E>) -> Hash256 {
+        attestation.tree_hash_root()
+    }
+
+    /// Returns the key used for a signed aggregate.
+    pub fn aggregate_key<E: EthSpec>(aggregate: &SignedAggregateAndProof<E>) -> Hash256 {
+        aggregate.tree_hash_root()
+    }
+
+    /// Returns `true` if a message with the given key has been verified recently.
+    pub fn contains(&self, key: &Hash256) -> bool {
+        self.seen.as_ref().map_or(false, |seen| seen.contains(key))
+    }
+
+    /// Records that the message with the given key has been verified.
+    pub fn insert(&mut self, key: Hash256) {
+        if let Some(seen) = &mut self.seen {
+            seen.put(key, ());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use types::{BeaconBlock, MinimalEthSpec, Signature, Slot};
+
+    type E = MinimalEthSpec;
+
+    fn block(slot: u64) -> SignedBeaconBlock<E> {
+        let mut message = BeaconBlock::empty(&E::default_spec());
+        message.slot = Slot::new(slot);
+
+        SignedBeaconBlock {
+            message,
+            signature: Signature::empty_signature(),
+        }
+    }
+
+    #[test]
+    fn duplicate_block_is_dropped() {
+        let mut cache = GossipCache::new(16);
+        let first = block(1);
+
+        let key = GossipCache::block_key(&first);
+        assert!(!cache.contains(&key), "first copy should be verified");
+        cache.insert(key);
+
+        let duplicate = first.clone();
+        assert!(
+            cache.contains(&GossipCache::block_key(&duplicate)),
+            "second copy should be dropped"
+        );
+        assert!(!cache.contains(&GossipCache::block_key(&block(2))));
+    }
+
+    #[test]
+    fn least_recently_seen_is_evicted() {
+        let mut cache = GossipCache::new(2);
+
+        for slot in 0..3 {
+            cache.insert(GossipCache::block_key(&block(slot)));
+        }
+
+        assert!(!cache.contains(&GossipCache::block_key(&block(0))));
+        assert!(cache.contains(&GossipCache::block_key(&block(1))));
+        assert!(cache.contains(&GossipCache::block_key(&block(2))));
+    }
+
+    #[test]
+    fn zero_capacity_disables_cache() {
+        let mut cache = GossipCache::new(0);
+        let key = GossipCache::block_key(&block(1));
+
+        cache.insert(key);
+        assert!(!cache.contains(&key));
+    }
+}
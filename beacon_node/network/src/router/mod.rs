@@ -4,21 +4,23 @@
 //! and processes those that are
 #![allow(clippy::unit_arg)]
 
+mod gossip_cache;
 pub mod processor;
 
-use crate::error;
 use crate::service::NetworkMessage;
+use crate::{error, metrics};
 use beacon_chain::{BeaconChain, BeaconChainTypes, BlockError};
 use eth2_libp2p::{
     rpc::{RPCError, RequestId},
     MessageId, NetworkGlobals, PeerId, PeerRequestId, PubsubMessage, Request, Response,
 };
 use futures::prelude::*;
+use gossip_cache::GossipCache;
 use processor::Processor;
 use slog::{debug, info, o, trace, warn};
 use std::sync::Arc;
 use tokio::sync::mpsc;
-use types::EthSpec;
+use types::{EthSpec, Hash256};
 
 /// Handles messages received from the network and client and organises syncing. This
 /// functionality of this struct is to validate an decode messages from the network before
@@ -32,6 +34,8 @@ pub struct Router<T: BeaconChainTypes> {
     /// Processes validated and decoded messages from the network. Has direct access to the
     /// sync manager.
     processor: Processor<T>,
+    /// Recently verified gossip messages, used to drop duplicates without re-verifying them.
+    gossip_cache: GossipCache,
     /// The `Router` logger.
     log: slog::Logger,
 }
@@ -74,6 +78,7 @@ impl<T: BeaconChainTypes> Router<T> {
         beacon_chain: Arc<BeaconChain<T>>,
         network_globals: Arc<NetworkGlobals<T::EthSpec>>,
         network_send: mpsc::UnboundedSender<NetworkMessage<T::EthSpec>>,
+        gossip_cache_size: usize,
         executor: environment::TaskExecutor,
         log: slog::Logger,
     ) -> error::Result<mpsc::UnboundedSender<RouterMessage<T::EthSpec>>> {
@@ -96,6 +101,7 @@ impl<T: BeaconChainTypes> Router<T> {
             network_send,
             network_globals,
             processor,
+            gossip_cache: GossipCache::new(gossip_cache_size),
             log: message_handler_log,
         };
 
@@ -218,16 +224,25 @@ impl<T: BeaconChainTypes> Router<T> {
         match gossip_message {
             // Attestations should never reach the router.
             PubsubMessage::AggregateAndProofAttestation(aggregate_and_proof) => {
+                let key = GossipCache::aggregate_key(&aggregate_and_proof);
+                if self.is_duplicate(&key) {
+                    return;
+                }
                 if let Some(gossip_verified) = self
                     .processor
                     .verify_aggregated_attestation_for_gossip(peer_id.clone(), *aggregate_and_proof)
                 {
+                    self.gossip_cache.insert(key);
                     self.propagate_message(id, peer_id.clone());
                     self.processor
                         .import_aggregated_attestation(peer_id, gossip_verified);
                 }
             }
             PubsubMessage::Attestation(subnet_attestation) => {
+                let key = GossipCache::attestation_key(&subnet_attestation.1);
+                if self.is_duplicate(&key) {
+                    return;
+                }
                 if let Some(gossip_verified) =
                     self.processor.verify_unaggregated_attestation_for_gossip(
                         peer_id.clone(),
@@ -235,14 +250,20 @@ impl<T: BeaconChainTypes> Router<T> {
                         subnet_attestation.0,
                     )
                 {
+                    self.gossip_cache.insert(key);
                     self.propagate_message(id, peer_id.clone());
                     self.processor
                         .import_unaggregated_attestation(peer_id, gossip_verified);
                 }
             }
             PubsubMessage::BeaconBlock(block) => {
+                let key = GossipCache::block_key(&block);
+                if self.is_duplicate(&key) {
+                    return;
+                }
                 match self.processor.should_forward_block(&peer_id, block) {
                     Ok(verified_block) => {
+                        self.gossip_cache.insert(key);
                         info!(self.log, "New block received"; "slot" => verified_block.block.slot(), "hash" => verified_block.block_root.to_string());
                         self.propagate_message(id, peer_id.clone());
                         self.processor.on_block_gossip(peer_id, verified_block);
@@ -298,6 +319,17 @@ impl<T: BeaconChainTypes> Router<T> {
         }
     }
 
+    /// Returns `true` if a gossip message with the given key was verified recently, in which case
+    /// it should be dropped.
+    fn is_duplicate(&self, key: &Hash256) -> bool {
+        let duplicate = self.gossip_cache.contains(key);
+        if duplicate {
+            trace!(self.log, "Dropping duplicate gossip message"; "root" => format!("{}", key));
+            metrics::inc_counter(&metrics::GOSSIP_DUPLICATES_DROPPED);
+        }
+        duplicate
+    }
+
     /// Informs the network service that the message should be forwarded to other peers.
     fn propagate_message(&mut self, message_id: MessageId, propagation_source: PeerId) {
         self.network_send
@@ -313,3 +345,77 @@ impl<T: BeaconChainTypes> Router<T> {
             });
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use beacon_chain::test_utils::BeaconChainHarness;
+    use eth2_libp2p::discovery::{build_enr, CombinedKey, CombinedKeyExt, Keypair};
+    use eth2_libp2p::NetworkConfig;
+    use sloggers::{null::NullLoggerBuilder, Build};
+    use store::config::StoreConfig;
+    use types::{test_utils::generate_deterministic_keypairs, EnrForkId, MinimalEthSpec};
+
+    type E = MinimalEthSpec;
+
+    /// Sends a valid gossip block to a router that has already recorded the root of the block as
+    /// verified, returning `true` if the router asked the network service to propagate it.
+    fn propagates_cached_block(gossip_cache_size: usize) -> bool {
+        let log = NullLoggerBuilder.build().expect("logger should build");
+
+        let harness = BeaconChainHarness::new(
+            MinimalEthSpec,
+            generate_deterministic_keypairs(8),
+            StoreConfig::default(),
+        );
+        harness.advance_slot();
+        let (block, _) = harness.get_block();
+
+        let config = NetworkConfig::default();
+        let enr_key = CombinedKey::from_libp2p(&Keypair::generate_secp256k1()).unwrap();
+        let enr = build_enr::<E>(&enr_key, &config, EnrForkId::default()).unwrap();
+        let network_globals = Arc::new(NetworkGlobals::new(enr, 0, 0, &log));
+
+        let (_signal, exit) = exit_future::signal();
+        let executor =
+            environment::TaskExecutor::new(tokio::runtime::Handle::current(), exit, log.clone());
+        let (network_send, mut network_recv) = mpsc::unbounded_channel();
+
+        let mut router = Router {
+            network_send: network_send.clone(),
+            network_globals: network_globals.clone(),
+            processor: Processor::new(
+                executor,
+                Arc::new(harness.chain),
+                network_globals,
+                network_send,
+                &log,
+            ),
+            gossip_cache: GossipCache::new(gossip_cache_size),
+            log,
+        };
+
+        router.gossip_cache.insert(GossipCache::block_key(&block));
+        router.handle_gossip(
+            MessageId("block".into()),
+            PeerId::random(),
+            PubsubMessage::BeaconBlock(Box::new(block)),
+        );
+
+        let mut propagated = false;
+        while let Some(Some(message)) = network_recv.recv().now_or_never() {
+            propagated |= matches!(message, NetworkMessage::Propagate { .. });
+        }
+        propagated
+    }
+
+    #[tokio::test]
+    async fn drops_recently_verified_block() {
+        assert!(!propagates_cached_block(16));
+    }
+
+    #[tokio::test]
+    async fn zero_gossip_cache_size_verifies_every_block() {
+        assert!(propagates_cached_block(0));
+    }
+}
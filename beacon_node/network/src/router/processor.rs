@@ -10,7 +10,7 @@ use beacon_chain::{
     ForkChoiceError, GossipVerifiedBlock,
 };
 use eth2_libp2p::rpc::*;
-use eth2_libp2p::{NetworkGlobals, PeerId, PeerRequestId, Request, Response};
+use eth2_libp2p::{NetworkGlobals, PeerAction, PeerId, PeerRequestId, Request, Response};
 use itertools::process_results;
 use slog::{debug, error, o, trace, warn};
 use ssz::Encode;
@@ -508,10 +508,25 @@ impl<T: BeaconChainTypes> Processor<T> {
     ) -> Result<GossipVerifiedBlock<T>, BlockError> {
         let result = self.chain.verify_block_for_gossip(*block.clone());
 
-        if let Err(BlockError::ParentUnknown(_)) = result {
-            // if we don't know the parent, start a parent lookup
-            // TODO: Modify the return to avoid the block clone.
-            self.send_to_sync(SyncMessage::UnknownBlock(peer_id.clone(), block));
+        match &result {
+            Ok(_) => self.network.reward_peer(peer_id.clone()),
+            Err(BlockError::ParentUnknown(_)) => {
+                // if we don't know the parent, start a parent lookup
+                // TODO: Modify the return to avoid the block clone.
+                self.send_to_sync(SyncMessage::UnknownBlock(peer_id.clone(), block));
+            }
+            Err(BlockError::StateRootMismatch { .. })
+            | Err(BlockError::IncorrectBlockProposer { .. })
+            | Err(BlockError::ProposalSignatureInvalid)
+            | Err(BlockError::UnknownValidator(_))
+            | Err(BlockError::InvalidSignature)
+            | Err(BlockError::BlockIsNotLaterThanParent { .. })
+            | Err(BlockError::PerBlockProcessingError(_)) => {
+                // The peer has published an invalid consensus message.
+                self.network
+                    .penalize_peer(peer_id.clone(), PeerAction::MidToleranceError);
+            }
+            Err(_) => {}
         }
         result
     }
@@ -623,6 +638,8 @@ impl<T: BeaconChainTypes> Processor<T> {
                  *
                  * The peer has published an invalid consensus message.
                  */
+                self.network
+                    .penalize_peer(peer_id, PeerAction::MidToleranceError);
             }
             AttnError::EmptyAggregationBitfield => {
                 /*
@@ -650,6 +667,8 @@ impl<T: BeaconChainTypes> Processor<T> {
                  *
                  * The peer has published an invalid consensus message.
                  */
+                self.network
+                    .penalize_peer(peer_id, PeerAction::MidToleranceError);
             }
             AttnError::AggregatorNotInCommittee { .. } => {
                 /*
@@ -665,6 +684,8 @@ impl<T: BeaconChainTypes> Processor<T> {
                  *
                  * The peer has published an invalid consensus message.
                  */
+                self.network
+                    .penalize_peer(peer_id, PeerAction::MidToleranceError);
             }
             AttnError::AttestationAlreadyKnown { .. } => {
                 /*
@@ -696,6 +717,8 @@ impl<T: BeaconChainTypes> Processor<T> {
                  *
                  * The peer has published an invalid consensus message.
                  */
+                self.network
+                    .penalize_peer(peer_id, PeerAction::MidToleranceError);
             }
             AttnError::UnknownHeadBlock { beacon_block_root } => {
                 // Note: its a little bit unclear as to whether or not this block is unknown or
@@ -730,6 +753,8 @@ impl<T: BeaconChainTypes> Processor<T> {
                  *
                  * The peer has published an invalid consensus message.
                  */
+                self.network
+                    .penalize_peer(peer_id, PeerAction::HighToleranceError);
             }
             AttnError::BadTargetEpoch => {
                 /*
@@ -738,6 +763,8 @@ impl<T: BeaconChainTypes> Processor<T> {
                  *
                  * The peer has published an invalid consensus message.
                  */
+                self.network
+                    .penalize_peer(peer_id, PeerAction::MidToleranceError);
             }
             AttnError::NoCommitteeForSlotAndIndex { .. } => {
                 /*
@@ -745,6 +772,8 @@ impl<T: BeaconChainTypes> Processor<T> {
                  *
                  * The peer has published an invalid consensus message.
                  */
+                self.network
+                    .penalize_peer(peer_id, PeerAction::MidToleranceError);
             }
             AttnError::NotExactlyOneAggregationBitSet(_) => {
                 /*
@@ -752,6 +781,8 @@ impl<T: BeaconChainTypes> Processor<T> {
                  *
                  * The peer has published an invalid consensus message.
                  */
+                self.network
+                    .penalize_peer(peer_id, PeerAction::MidToleranceError);
             }
             AttnError::AttestsToFutureBlock { .. } => {
                 /*
@@ -759,6 +790,8 @@ impl<T: BeaconChainTypes> Processor<T> {
                  *
                  * The peer has published an invalid consensus message.
                  */
+                self.network
+                    .penalize_peer(peer_id, PeerAction::HighToleranceError);
             }
 
             AttnError::InvalidSubnetId { received, expected } => {
@@ -778,6 +811,8 @@ impl<T: BeaconChainTypes> Processor<T> {
                  *
                  * The peer has published an invalid consensus message.
                  */
+                self.network
+                    .penalize_peer(peer_id, PeerAction::HighToleranceError);
             }
             AttnError::BeaconChainError(e) => {
                 /*
@@ -1117,6 +1152,27 @@ impl<T: EthSpec> HandlerNetworkContext<T> {
         self.inform_network(NetworkMessage::Disconnect { peer_id });
     }
 
+    /// Lowers the reputation of a peer that has sent us an invalid message.
+    pub fn penalize_peer(&mut self, peer_id: PeerId, reason: PeerAction) {
+        debug!(
+            &self.log,
+            "Penalizing peer";
+            "peer_id" => format!("{:?}", peer_id),
+        );
+        self.inform_network(NetworkMessage::ReportPeer {
+            peer_id,
+            action: reason,
+        });
+    }
+
+    /// Raises the reputation of a peer that has sent us a valid message.
+    pub fn reward_peer(&mut self, peer_id: PeerId) {
+        self.inform_network(NetworkMessage::ReportPeer {
+            peer_id,
+            action: PeerAction::ValidMessage,
+        });
+    }
+
     pub fn send_processor_request(&mut self, peer_id: PeerId, request: Request) {
         self.inform_network(NetworkMessage::SendRequest {
             peer_id,
@@ -11,7 +11,7 @@ use eth2_libp2p::{
     rpc::{RPCResponseErrorCode, RequestId},
     Libp2pEvent, PeerRequestId, PubsubMessage, Request, Response,
 };
-use eth2_libp2p::{BehaviourEvent, MessageId, NetworkGlobals, PeerId};
+use eth2_libp2p::{BehaviourEvent, MessageId, NetworkGlobals, PeerAction, PeerId};
 use futures::prelude::*;
 use rest_types::ValidatorSubscription;
 use slog::{debug, error, info, o, trace, warn};
@@ -92,6 +92,7 @@ impl<T: BeaconChainTypes> NetworkService<T> {
             beacon_chain.clone(),
             network_globals.clone(),
             network_send.clone(),
+            config.gossip_cache_size,
             executor.clone(),
             network_log.clone(),
         )?;
@@ -206,6 +207,12 @@ fn spawn_service<T: BeaconChainTypes>(
                                 std::time::Duration::from_secs(BAN_PEER_TIMEOUT),
                             );
                         }
+                        NetworkMessage::BanPeer { peer_id, duration } => {
+                            service.libp2p.disconnect_and_ban_peer(peer_id, duration);
+                        }
+                        NetworkMessage::ReportPeer { peer_id, action } => {
+                            service.libp2p.swarm.peer_manager().report_peer(&peer_id, action);
+                        }
                         NetworkMessage::Subscribe { subscriptions } => {
                             if let Err(e) = service
                                 .attestation_service
@@ -415,6 +422,10 @@ pub enum NetworkMessage<T: EthSpec> {
     },
     /// Disconnect and bans a peer id.
     Disconnect { peer_id: PeerId },
+    /// Disconnects and bans a peer id for the given `duration`, after which it may reconnect.
+    BanPeer { peer_id: PeerId, duration: Duration },
+    /// Adjusts the reputation of a peer, disconnecting it if it falls below the threshold.
+    ReportPeer { peer_id: PeerId, action: PeerAction },
 }
 
 /// Inspects the `messages` that were being sent to the network and updates Prometheus metrics.
@@ -9,6 +9,7 @@ use attestation::AttMaxCover;
 use attestation_id::AttestationId;
 use max_cover::maximum_cover;
 use parking_lot::RwLock;
+use ssz::Encode;
 use state_processing::per_block_processing::errors::AttestationValidationError;
 use state_processing::per_block_processing::{
     get_slashable_indices, get_slashable_indices_modular, verify_attestation_for_block_inclusion,
@@ -21,7 +22,7 @@ use std::ptr;
 use types::{
     typenum::Unsigned, Attestation, AttesterSlashing, BeaconState, BeaconStateError, ChainSpec,
     EthSpec, Fork, ForkVersion, Hash256, ProposerSlashing, RelativeEpoch, SignedVoluntaryExit,
-    Validator,
+    Slot, Validator,
 };
 
 #[derive(Default, Debug)]
@@ -129,7 +130,7 @@ impl<T: EthSpec> OperationPool<T> {
         let total_active_balance = state
             .get_total_balance(&active_indices, spec)
             .map_err(OpPoolError::GetAttestationsTotalBalanceError)?;
-        let valid_attestations = reader
+        let mut valid_attestations = reader
             .iter()
             .filter(|(key, _)| {
                 key.domain_bytes_match(&prev_domain_bytes)
@@ -147,10 +148,16 @@ impl<T: EthSpec> OperationPool<T> {
                 .is_ok()
             })
             .filter(validity_filter)
-            .flat_map(|att| AttMaxCover::new(att, state, total_active_balance, spec));
+            .collect::<Vec<_>>();
+
+        // The map is iterated in an arbitrary order, which would otherwise decide between
+        // attestations with equal rewards, and therefore the contents of the block.
+        valid_attestations.sort_by_cached_key(|attestation| attestation_packing_key(attestation));
 
         Ok(maximum_cover(
-            valid_attestations,
+            valid_attestations
+                .into_iter()
+                .flat_map(|att| AttMaxCover::new(att, state, total_active_balance, spec)),
             T::MaxAttestations::to_usize(),
         ))
     }
@@ -334,6 +341,21 @@ impl<T: EthSpec> OperationPool<T> {
     }
 }
 
+/// Returns a key which orders attestations by slot, then committee index, then the position of
+/// their first attester in the committee.
+///
+/// The SSZ encoding breaks any remaining ties, so that the order is total.
+fn attestation_packing_key<T: EthSpec>(
+    attestation: &Attestation<T>,
+) -> (Slot, u64, Option<usize>, Vec<u8>) {
+    (
+        attestation.data.slot,
+        attestation.data.index,
+        attestation.aggregation_bits.iter().position(|bit| bit),
+        attestation.as_ssz_bytes(),
+    )
+}
+
 /// Filter up to a maximum number of operations out of an iterator.
 fn filter_limit_operations<'a, T: 'a, I, F>(operations: I, filter: F, limit: usize) -> Vec<T>
 where
@@ -749,6 +771,68 @@ mod release_tests {
         }
     }
 
+    /// Attestations with equal rewards must be packed in the same order by every pool, regardless
+    /// of the iteration order of the pool's map.
+    #[test]
+    fn attestation_packing_is_deterministic() {
+        let step_size = 2;
+        let (ref mut state, ref keypairs, ref spec) =
+            attestation_test_state::<MainnetEthSpec>(step_size);
+
+        let slot = state.slot - 1;
+        let committees = state
+            .get_beacon_committees_at_slot(slot)
+            .unwrap()
+            .into_iter()
+            .map(BeaconCommittee::into_owned)
+            .collect::<Vec<_>>();
+
+        let mut attestations = vec![];
+        for bc in &committees {
+            for i in (0..bc.committee.len()).step_by(step_size) {
+                attestations.push(signed_attestation(
+                    &bc.committee,
+                    bc.index,
+                    keypairs,
+                    i..i + step_size,
+                    slot,
+                    state,
+                    spec,
+                    if i == 0 { None } else { Some(0) },
+                ));
+            }
+        }
+        assert!(attestations.len() > <MainnetEthSpec as EthSpec>::MaxAttestations::to_usize());
+
+        state.slot += spec.min_attestation_inclusion_delay;
+
+        let block_root = || {
+            let op_pool = OperationPool::new();
+            for att in &attestations {
+                op_pool
+                    .insert_attestation(
+                        att.clone(),
+                        &state.fork,
+                        state.genesis_validators_root,
+                        spec,
+                    )
+                    .unwrap();
+            }
+
+            let mut block = BeaconBlock::<MainnetEthSpec>::empty(spec);
+            block.body.attestations = op_pool
+                .get_attestations(state, |_| true, spec)
+                .expect("should have best attestations")
+                .into();
+            block.canonical_root()
+        };
+
+        let first = block_root();
+        for _ in 0..4 {
+            assert_eq!(block_root(), first);
+        }
+    }
+
     #[test]
     fn attestation_rewards() {
         let small_step_size = 2;
@@ -113,6 +113,7 @@ pub async fn route<T: BeaconChainTypes>(
         (&Method::GET, "/validator/duties/active") => {
             validator::get_active_validator_duties::<T>(req, beacon_chain)
         }
+        (&Method::GET, "/validator/proposers") => validator::get_proposers::<T>(req, beacon_chain),
         (&Method::GET, "/validator/block") => {
             let timer = metrics::start_timer(&metrics::VALIDATOR_GET_BLOCK_REQUEST_RESPONSE_TIME);
             let response = validator::get_new_beacon_block::<T>(req, beacon_chain, log);
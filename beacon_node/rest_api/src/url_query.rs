@@ -109,6 +109,13 @@ impl<'a> UrlQuery<'a> {
             .and_then(|(_key, value)| parse_hex_ssz_bytes(&value))
     }
 
+    /// Returns the value of the first occurrence of the `graffiti` key, if any.
+    pub fn graffiti(self) -> Result<Option<[u8; 32]>, ApiError> {
+        self.first_of_opt(&["graffiti"])
+            .map(|(_key, value)| parse_hex_ssz_bytes(&value))
+            .transpose()
+    }
+
     /// Returns the value of the first occurrence of the `attestation_data` key.
     pub fn attestation_data(self) -> Result<AttestationData, ApiError> {
         self.first_of(&["attestation_data"])
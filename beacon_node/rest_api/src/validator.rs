@@ -10,7 +10,7 @@ use eth2_libp2p::PubsubMessage;
 use hyper::{Body, Request};
 use network::NetworkMessage;
 use rayon::prelude::*;
-use rest_types::{ValidatorDutiesRequest, ValidatorDutyBytes, ValidatorSubscription};
+use rest_types::{ProposerDuty, ValidatorDutiesRequest, ValidatorDutyBytes, ValidatorSubscription};
 use slog::{error, info, trace, warn, Logger};
 use std::sync::Arc;
 use types::beacon_state::EthSpec;
@@ -130,6 +130,42 @@ pub fn get_active_validator_duties<T: BeaconChainTypes>(
     ResponseBuilder::new(&req)?.body_no_ssz(&duties)
 }
 
+/// HTTP Handler to retrieve the block proposer for each slot of the given epoch.
+pub fn get_proposers<T: BeaconChainTypes>(
+    req: Request<Body>,
+    beacon_chain: Arc<BeaconChain<T>>,
+) -> ApiResult {
+    let query = UrlQuery::from_request(&req)?;
+
+    let epoch = query.epoch()?;
+
+    let mut state = get_state_for_epoch(&beacon_chain, epoch, StateSkipConfig::WithoutStateRoots)?;
+
+    let relative_epoch = RelativeEpoch::from_epoch(state.current_epoch(), epoch)
+        .map_err(|_| ApiError::ServerError(String::from("Loaded state is in the wrong epoch")))?;
+
+    state
+        .build_committee_cache(relative_epoch, &beacon_chain.spec)
+        .map_err(|e| ApiError::ServerError(format!("Unable to build committee cache: {:?}", e)))?;
+
+    let proposers = epoch
+        .slot_iter(T::EthSpec::slots_per_epoch())
+        .map(|slot| {
+            state
+                .get_beacon_proposer_index(slot, &beacon_chain.spec)
+                .map(|validator_index| ProposerDuty {
+                    slot,
+                    validator_index: validator_index as u64,
+                })
+                .map_err(|e| {
+                    ApiError::ServerError(format!("Unable to get proposer index: {:?}", e))
+                })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    ResponseBuilder::new(&req)?.body_no_ssz(&proposers)
+}
+
 /// Helper function to return the state that can be used to determine the duties for some `epoch`.
 pub fn get_state_for_epoch<T: BeaconChainTypes>(
     beacon_chain: &BeaconChain<T>,
@@ -279,9 +315,10 @@ pub fn get_new_beacon_block<T: BeaconChainTypes>(
 
     let slot = query.slot()?;
     let randao_reveal = query.randao_reveal()?;
+    let graffiti = query.graffiti()?;
 
     let (new_block, _state) = beacon_chain
-        .produce_block(randao_reveal, slot)
+        .produce_block(randao_reveal, slot, graffiti)
         .map_err(|e| {
             error!(
                 log,
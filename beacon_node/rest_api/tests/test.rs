@@ -20,9 +20,9 @@ use types::{
         build_double_vote_attester_slashing, build_proposer_slashing,
         generate_deterministic_keypair, AttesterSlashingTestTask, ProposerSlashingTestTask,
     },
-    BeaconBlock, BeaconState, ChainSpec, Domain, Epoch, EthSpec, MinimalEthSpec, PublicKey,
-    RelativeEpoch, Signature, SignedAggregateAndProof, SignedBeaconBlock, SignedRoot, Slot,
-    SubnetId, Validator,
+    BeaconBlock, BeaconState, BitList, ChainSpec, Domain, Epoch, EthSpec, MinimalEthSpec,
+    PublicKey, RelativeEpoch, Signature, SignedAggregateAndProof, SignedBeaconBlock, SignedRoot,
+    Slot, SubnetId, Validator,
 };
 use version;
 
@@ -219,6 +219,35 @@ fn validator_produce_attestation() {
         )
         .expect("should sign attestation");
 
+    // Try publishing the signed attestation with an aggregation bitfield that is longer than the
+    // committee.
+    let mut over_long_attestation = attestation.clone();
+    over_long_attestation.aggregation_bits =
+        BitList::with_capacity(attestation.aggregation_bits.len() + 1)
+            .expect("should create bitfield");
+    over_long_attestation
+        .aggregation_bits
+        .set(
+            duties
+                .attestation_committee_position
+                .expect("should have committee position"),
+            true,
+        )
+        .expect("should set attestation bit");
+    let publish_status = env
+        .runtime()
+        .block_on(
+            remote_node
+                .http
+                .validator()
+                .publish_attestations(vec![(over_long_attestation, subnet_id)]),
+        )
+        .expect("should publish attestation with over-long bitfield");
+    assert!(
+        !publish_status.is_valid(),
+        "the attestation with an over-long bitfield should not be valid"
+    );
+
     // Try publishing the valid attestation.
     let publish_status = env
         .runtime()
@@ -414,6 +443,53 @@ fn check_duties<T: BeaconChainTypes>(
     assert_eq!(all_proposer_slots, all_slots);
 }
 
+#[test]
+fn validator_proposers() {
+    let mut env = build_env();
+
+    let spec = &E::default_spec();
+
+    let node = build_node(&mut env, testing_client_config());
+    let remote_node = node.remote_node().expect("should produce remote node");
+
+    let beacon_chain = node
+        .client
+        .beacon_chain()
+        .expect("client should have beacon chain");
+
+    let epoch = Epoch::new(0);
+
+    let proposers = env
+        .runtime()
+        .block_on(remote_node.http.validator().get_proposers(epoch))
+        .expect("should fetch proposers from http api");
+
+    let mut state = beacon_chain
+        .state_at_slot(
+            epoch.start_slot(E::slots_per_epoch()),
+            StateSkipConfig::WithStateRoots,
+        )
+        .expect("should get state at slot");
+
+    state.build_all_caches(spec).expect("should build caches");
+
+    assert_eq!(
+        proposers.iter().map(|p| p.slot).collect::<Vec<_>>(),
+        epoch.slot_iter(E::slots_per_epoch()).collect::<Vec<_>>(),
+        "there should be a proposer for each slot in the epoch"
+    );
+
+    for proposer in proposers {
+        let expected_proposer = state
+            .get_beacon_proposer_index(proposer.slot, spec)
+            .expect("should know proposer");
+        assert_eq!(
+            proposer.validator_index, expected_proposer as u64,
+            "proposer index should match"
+        );
+    }
+}
+
 #[test]
 fn validator_block_post() {
     let mut env = build_env();
@@ -449,7 +525,7 @@ fn validator_block_post() {
             remote_node
                 .http
                 .validator()
-                .produce_block(slot, randao_reveal),
+                .produce_block(slot, randao_reveal, None),
         )
         .expect("should fetch block from http api");
 
@@ -536,15 +612,17 @@ fn validator_block_get() {
             remote_node
                 .http
                 .validator()
-                .produce_block(slot, randao_reveal.clone()),
+                .produce_block(slot, randao_reveal.clone(), None),
         )
         .expect("should fetch block from http api");
 
+    assert_eq!(block.slot, slot, "block should be at the requested slot");
+
     let (expected_block, _state) = node
         .client
         .beacon_chain()
         .expect("client should have beacon chain")
-        .produce_block(randao_reveal, slot)
+        .produce_block(randao_reveal, slot, None)
         .expect("should produce block");
 
     assert_eq!(
@@ -917,10 +995,23 @@ fn get_committees() {
         .block_on(remote_node.http.beacon().get_committees(epoch))
         .expect("should fetch from http api");
 
-    let expected = chain
-        .head()
-        .expect("should get head")
-        .beacon_state
+    let state = chain.head().expect("should get head").beacon_state;
+
+    // The committees should partition the active validator set.
+    let mut committee_members = result
+        .iter()
+        .flat_map(|c| c.committee.iter().copied())
+        .collect::<Vec<_>>();
+    committee_members.sort();
+    assert_eq!(
+        committee_members,
+        state
+            .get_active_validator_indices(epoch, &chain.spec)
+            .expect("should get active validators"),
+        "committees should partition the active validator set"
+    );
+
+    let expected = state
         .get_beacon_committees_at_epoch(RelativeEpoch::Current)
         .expect("should get committees")
         .iter()
@@ -934,6 +1025,32 @@ fn get_committees() {
     assert_eq!(result, expected, "result should be as expected");
 }
 
+#[test]
+fn get_metrics() {
+    let mut env = build_env();
+
+    let node = build_node(&mut env, testing_client_config());
+    let remote_node = node.remote_node().expect("should produce remote node");
+
+    let result = env
+        .runtime()
+        .block_on(remote_node.http.get_metrics())
+        .expect("should fetch from http api");
+
+    for name in &[
+        "slotclock_present_slot",
+        "beacon_head_state_slot",
+        "beacon_head_state_finalized_epoch",
+    ] {
+        assert!(
+            result.contains(name),
+            "metrics should contain {}, got:\n{}",
+            name,
+            result
+        );
+    }
+}
+
 #[test]
 fn get_fork_choice() {
     let mut env = build_env();
@@ -1,4 +1,4 @@
-use clap::{App, Arg};
+use clap::{App, AppSettings, Arg};
 
 pub fn cli_app<'a, 'b>() -> App<'a, 'b> {
     App::new("beacon_node")
@@ -8,6 +8,22 @@ pub fn cli_app<'a, 'b>() -> App<'a, 'b> {
         .about("The primary component which connects to the Ethereum 2.0 P2P network and \
                 downloads, verifies and stores blocks. Provides a HTTP API for querying \
                 the beacon chain and publishing messages to the network.")
+        // Allow `db` and `from-keystores` without the arguments required to run the node.
+        .setting(AppSettings::SubcommandsNegateReqs)
+        .subcommand(crate::db::cli_app())
+        .subcommand(
+            App::new("from-keystores")
+                .about("Runs the beacon node with an interop genesis state which has one \
+                        validator for each keystore in DIR, instead of VALIDATOR_COUNT.")
+                .arg(Arg::with_name("keystore-dir")
+                     .value_name("DIR")
+                     .required(true)
+                     .help("The directory containing the keystores."))
+                .arg(Arg::with_name("genesis-time")
+                     .value_name("GENESIS_TIME")
+                     .required(true)
+                     .help("The genesis time for the given state."))
+        )
         /*
          * Configuration directory locations.
          */
@@ -67,6 +83,16 @@ pub fn cli_app<'a, 'b>() -> App<'a, 'b> {
                 .default_value("50")
                 .takes_value(true),
         )
+        .arg(
+            Arg::with_name("gossip-cache-size")
+                .long("gossip-cache-size")
+                .value_name("SIZE")
+                .help("The number of recently verified gossip blocks and attestations to \
+                       remember. Duplicates of these messages are dropped without being \
+                       verified again. A size of 0 disables the cache.")
+                .default_value("4096")
+                .takes_value(true),
+        )
         .arg(
             Arg::with_name("boot-nodes")
                 .long("boot-nodes")
@@ -125,6 +151,32 @@ pub fn cli_app<'a, 'b>() -> App<'a, 'b> {
                        without an ENR.")
                 .takes_value(true),
         )
+        .arg(
+            Arg::with_name("libp2p-dial-backoff")
+                .long("libp2p-dial-backoff")
+                .value_name("SECONDS")
+                .help("The delay before redialing a --libp2p-addresses peer after its first failed \
+                       dial. The delay doubles after each subsequent failure.")
+                .default_value("1")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("libp2p-dial-max-backoff")
+                .long("libp2p-dial-max-backoff")
+                .value_name("SECONDS")
+                .help("The maximum delay between redials of a --libp2p-addresses peer.")
+                .default_value("300")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("libp2p-dial-attempts")
+                .long("libp2p-dial-attempts")
+                .value_name("COUNT")
+                .help("The number of failed dials after which a --libp2p-addresses peer is no \
+                       longer redialed.")
+                .default_value("10")
+                .takes_value(true),
+        )
         .arg(
             Arg::with_name("disable-discovery")
                 .long("disable-discovery")
@@ -212,4 +264,14 @@ pub fn cli_app<'a, 'b>() -> App<'a, 'b> {
              .value_name("GENESIS_TIME")
              .required(true)
              .help("The genesis time for the given state."))
+        .arg(Arg::with_name("deposit-amounts")
+             .long("deposit-amounts")
+             .value_name("CSV")
+             .takes_value(true)
+             .help("A comma-separated list of deposit amounts in gwei, one for each validator \
+                    in the genesis state, in validator index order. Each amount must be at \
+                    least the minimum deposit amount and a multiple of the effective balance \
+                    increment. Validators which deposit less than the max effective balance are \
+                    not active at genesis. \
+                    [default: the max effective balance for every validator]"))
 }
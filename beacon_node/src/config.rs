@@ -2,13 +2,16 @@ use beacon_chain::builder::PUBKEY_CACHE_FILENAME;
 use clap::ArgMatches;
 use clap_utils::{parse_required, BAD_TESTNET_DIR_MESSAGE};
 use client::{config::DEFAULT_DATADIR, ClientConfig, ClientGenesis};
+use eth2_keystore::Keystore;
 use eth2_libp2p::{Enr, Multiaddr};
 use eth2_testnet_config::Eth2TestnetConfig;
+use genesis::{generate_deterministic_keypairs, interop_genesis_state_with_amounts};
 use slog::{crit, info, Logger};
+use ssz::Encode;
 use std::fs;
 use std::net::{IpAddr, Ipv4Addr, ToSocketAddrs};
 use std::net::{TcpListener, UdpSocket};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use types::{ChainSpec, EthSpec};
 
 pub const BEACON_NODE_DIR: &str = "beacon";
@@ -94,6 +97,12 @@ pub fn get_config<E: EthSpec>(
             .map_err(|_| format!("Invalid number of max peers: {}", max_peers_str))?;
     }
 
+    if let Some(gossip_cache_size_str) = cli_args.value_of("gossip-cache-size") {
+        client_config.network.gossip_cache_size = gossip_cache_size_str
+            .parse::<usize>()
+            .map_err(|_| format!("Invalid gossip cache size: {}", gossip_cache_size_str))?;
+    }
+
     if let Some(port_str) = cli_args.value_of("port") {
         let port = port_str
             .parse::<u16>()
@@ -127,6 +136,24 @@ pub fn get_config<E: EthSpec>(
             .collect::<Result<Vec<Multiaddr>, _>>()?;
     }
 
+    if let Some(backoff_str) = cli_args.value_of("libp2p-dial-backoff") {
+        client_config.network.dial_backoff_initial_secs = backoff_str
+            .parse::<u64>()
+            .map_err(|_| format!("Invalid libp2p dial backoff: {}", backoff_str))?;
+    }
+
+    if let Some(max_backoff_str) = cli_args.value_of("libp2p-dial-max-backoff") {
+        client_config.network.dial_backoff_max_secs = max_backoff_str
+            .parse::<u64>()
+            .map_err(|_| format!("Invalid libp2p dial max backoff: {}", max_backoff_str))?;
+    }
+
+    if let Some(attempts_str) = cli_args.value_of("libp2p-dial-attempts") {
+        client_config.network.dial_max_attempts = attempts_str
+            .parse::<usize>()
+            .map_err(|_| format!("Invalid libp2p dial attempts: {}", attempts_str))?;
+    }
+
     if let Some(enr_udp_port_str) = cli_args.value_of("enr-udp-port") {
         client_config.network.enr_udp_port = Some(
             enr_udp_port_str
@@ -289,12 +316,41 @@ pub fn get_config<E: EthSpec>(
         client_config.websocket_server.port = 0;
     }
 
-    let validator_count = parse_required(cli_args, "validator-count")?;
-    let genesis_time = parse_required(cli_args, "genesis-time")?;
+    let (validator_count, genesis_time) =
+        if let Some(sub_matches) = cli_args.subcommand_matches("from-keystores") {
+            if cli_args.is_present("validator-count") {
+                return Err("VALIDATOR_COUNT cannot be used with from-keystores".into());
+            }
 
-    client_config.genesis = ClientGenesis::Interop {
-        validator_count,
-        genesis_time,
+            let dir: PathBuf = parse_required(sub_matches, "keystore-dir")?;
+            (
+                count_keystores(&dir)?,
+                parse_required(sub_matches, "genesis-time")?,
+            )
+        } else {
+            (
+                parse_required(cli_args, "validator-count")?,
+                parse_required(cli_args, "genesis-time")?,
+            )
+        };
+
+    client_config.genesis = if let Some(csv) = cli_args.value_of("deposit-amounts") {
+        let amounts = parse_deposit_amounts(csv)?;
+        let genesis_state = interop_genesis_state_with_amounts::<E>(
+            &generate_deterministic_keypairs(validator_count),
+            &amounts,
+            genesis_time,
+            spec,
+        )?;
+
+        ClientGenesis::SszBytes {
+            genesis_state_bytes: genesis_state.as_ssz_bytes(),
+        }
+    } else {
+        ClientGenesis::Interop {
+            validator_count,
+            genesis_time,
+        }
     };
 
     /*
@@ -318,6 +374,47 @@ pub fn get_config<E: EthSpec>(
     Ok(client_config)
 }
 
+/// Returns the number of files directly within `dir` which contain a valid EIP-2335 keystore.
+///
+/// Other files (e.g., passwords) and subdirectories are ignored. Returns an error if there are no
+/// keystores.
+pub fn count_keystores(dir: &Path) -> Result<usize, String> {
+    let mut count = 0;
+
+    for entry in fs::read_dir(dir).map_err(|e| format!("Unable to read {:?}: {:?}", dir, e))? {
+        let path = entry
+            .map_err(|e| format!("Unable to read entry in {:?}: {:?}", dir, e))?
+            .path();
+
+        if path.is_file()
+            && fs::File::open(&path)
+                .ok()
+                .and_then(|file| Keystore::from_json_reader(file).ok())
+                .is_some()
+        {
+            count += 1;
+        }
+    }
+
+    if count == 0 {
+        Err(format!("No keystores found in {:?}", dir))
+    } else {
+        Ok(count)
+    }
+}
+
+/// Parses a comma-separated list of deposit amounts in gwei.
+pub fn parse_deposit_amounts(csv: &str) -> Result<Vec<u64>, String> {
+    csv.split(',')
+        .map(|amount| {
+            amount
+                .trim()
+                .parse()
+                .map_err(|e| format!("Invalid deposit amount {:?}: {:?}", amount, e))
+        })
+        .collect()
+}
+
 /// Gets the datadir which should be used.
 pub fn get_data_dir(cli_args: &ArgMatches) -> PathBuf {
     // Read the `--datadir` flag.
@@ -396,3 +493,53 @@ pub fn unused_port(transport: &str) -> Result<u16, String> {
     };
     Ok(local_addr.port())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use genesis::interop_genesis_state;
+    use tempfile::tempdir;
+    use types::MinimalEthSpec;
+    use validator_dir::insecure_keys::generate_deterministic_keystore;
+
+    #[test]
+    fn genesis_validator_count_from_keystore_dir() {
+        let dir = tempdir().unwrap();
+
+        for i in 0..3 {
+            let (keystore, _password) = generate_deterministic_keystore(i).unwrap();
+            let file = fs::File::create(dir.path().join(format!("keystore-{}.json", i))).unwrap();
+            keystore.to_json_writer(file).unwrap();
+        }
+        fs::write(dir.path().join("password.txt"), "not a keystore").unwrap();
+        fs::create_dir(dir.path().join("subdir")).unwrap();
+
+        let validator_count = count_keystores(dir.path()).unwrap();
+        assert_eq!(validator_count, 3);
+
+        let spec = MinimalEthSpec::default_spec();
+        let state = interop_genesis_state::<MinimalEthSpec>(
+            &generate_deterministic_keypairs(validator_count),
+            0,
+            &spec,
+        )
+        .unwrap();
+        assert_eq!(state.validators.len(), 3);
+    }
+
+    #[test]
+    fn empty_keystore_dir() {
+        let dir = tempdir().unwrap();
+        assert!(count_keystores(dir.path()).is_err());
+    }
+
+    #[test]
+    fn deposit_amounts_csv() {
+        assert_eq!(
+            parse_deposit_amounts("32000000000, 16000000000").unwrap(),
+            vec![32_000_000_000, 16_000_000_000]
+        );
+        assert!(parse_deposit_amounts("32000000000,").is_err());
+        assert!(parse_deposit_amounts("32 ETH").is_err());
+    }
+}
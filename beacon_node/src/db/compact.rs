@@ -0,0 +1,30 @@
+use super::{disk_size, open_existing};
+use clap::App;
+use std::path::PathBuf;
+use types::EthSpec;
+
+pub const CMD: &str = "compact";
+
+pub fn cli_app<'a, 'b>() -> App<'a, 'b> {
+    App::new(CMD).about(
+        "Compacts the databases, discarding deleted and overwritten values, and reports their \
+         size on disk before and after.",
+    )
+}
+
+pub fn cli_run<E: EthSpec>(databases: &[(&str, PathBuf)]) -> Result<(), String> {
+    for (name, path) in databases {
+        let db = open_existing::<E>(path)?;
+
+        let size_before = disk_size(path)?;
+        db.compact();
+        let size_after = disk_size(path)?;
+
+        println!(
+            "Compacted {} database {:?}: {} bytes before, {} bytes after",
+            name, path, size_before, size_after
+        );
+    }
+
+    Ok(())
+}
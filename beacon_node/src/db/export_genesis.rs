@@ -0,0 +1,175 @@
+use super::open_existing;
+use clap::{App, Arg, ArgMatches};
+use environment::RuntimeContext;
+use slog::Logger;
+use ssz::Encode;
+use std::fs::File;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use store::{HotColdDB, StoreConfig};
+use types::{ChainSpec, EthSpec};
+
+pub const CMD: &str = "export-genesis";
+pub const FORMAT_FLAG: &str = "format";
+pub const OUTPUT_FLAG: &str = "output";
+
+/// The encodings in which the genesis state may be exported.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Format {
+    Ssz,
+    Yaml,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, String> {
+        match s {
+            "ssz" => Ok(Format::Ssz),
+            "yaml" => Ok(Format::Yaml),
+            other => Err(format!("Unknown format: {}", other)),
+        }
+    }
+}
+
+pub fn cli_app<'a, 'b>() -> App<'a, 'b> {
+    App::new(CMD)
+        .about("Writes the genesis state stored in the databases to a file.")
+        .arg(
+            Arg::with_name(FORMAT_FLAG)
+                .long(FORMAT_FLAG)
+                .value_name("FORMAT")
+                .help("The encoding of the output file.")
+                .possible_values(&["ssz", "yaml"])
+                .default_value("ssz")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(OUTPUT_FLAG)
+                .value_name("OUT")
+                .help("The file to which the genesis state is written.")
+                .required(true)
+                .takes_value(true),
+        )
+}
+
+pub fn cli_run<E: EthSpec>(
+    matches: &ArgMatches,
+    hot_path: &Path,
+    freezer_path: &Path,
+    context: RuntimeContext<E>,
+) -> Result<(), String> {
+    let format: Format = clap_utils::parse_required(matches, FORMAT_FLAG)?;
+    let output: PathBuf = clap_utils::parse_required(matches, OUTPUT_FLAG)?;
+
+    export_genesis::<E>(
+        hot_path,
+        freezer_path,
+        format,
+        &output,
+        context.eth2_config.spec.clone(),
+        context.log().clone(),
+    )?;
+
+    println!("Wrote genesis state to {:?}", output);
+
+    Ok(())
+}
+
+/// Loads the genesis state from the hot and freezer databases and writes it to `output`.
+pub fn export_genesis<E: EthSpec>(
+    hot_path: &Path,
+    freezer_path: &Path,
+    format: Format,
+    output: &Path,
+    spec: ChainSpec,
+    log: Logger,
+) -> Result<(), String> {
+    // Check both databases exist, since opening the `HotColdDB` would create them.
+    drop(open_existing::<E>(hot_path)?);
+    drop(open_existing::<E>(freezer_path)?);
+
+    let store =
+        HotColdDB::<E, _, _>::open(hot_path, freezer_path, StoreConfig::default(), spec, log)
+            .map_err(|e| format!("Unable to open database: {:?}", e))?;
+
+    let genesis_state = store
+        .load_genesis_state()
+        .map_err(|e| format!("Unable to load genesis state: {:?}", e))?
+        .ok_or_else(|| "Database does not contain a genesis state".to_string())?;
+
+    let mut file =
+        File::create(output).map_err(|e| format!("Unable to create {:?}: {:?}", output, e))?;
+
+    match format {
+        Format::Ssz => file
+            .write_all(&genesis_state.as_ssz_bytes())
+            .map_err(|e| format!("Unable to write {:?}: {:?}", output, e)),
+        Format::Yaml => serde_yaml::to_writer(file, &genesis_state)
+            .map_err(|e| format!("Unable to write {:?}: {:?}", output, e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use genesis::{generate_deterministic_keypairs, interop_genesis_state};
+    use ssz::Decode;
+    use std::fs;
+    use tempfile::tempdir;
+    use types::{BeaconState, MinimalEthSpec};
+
+    type E = MinimalEthSpec;
+
+    #[test]
+    fn ssz_export_round_trip() {
+        let spec = E::default_spec();
+        let log = Logger::root(slog::Discard, slog::o!());
+        let dir = tempdir().unwrap();
+        let hot_path = dir.path().join("chain_db");
+        let freezer_path = dir.path().join("freezer_db");
+        let output = dir.path().join("genesis.ssz");
+
+        let mut genesis_state =
+            interop_genesis_state::<E>(&generate_deterministic_keypairs(8), 0, &spec).unwrap();
+        let genesis_state_root = genesis_state.update_tree_hash_cache().unwrap();
+
+        let store = HotColdDB::<E, _, _>::open(
+            &hot_path,
+            &freezer_path,
+            StoreConfig::default(),
+            spec.clone(),
+            log.clone(),
+        )
+        .unwrap();
+        store
+            .put_state(&genesis_state_root, &genesis_state)
+            .unwrap();
+        drop(store);
+
+        export_genesis::<E>(&hot_path, &freezer_path, Format::Ssz, &output, spec, log).unwrap();
+
+        let mut imported = BeaconState::<E>::from_ssz_bytes(&fs::read(&output).unwrap()).unwrap();
+        assert_eq!(
+            imported.update_tree_hash_cache().unwrap(),
+            genesis_state_root
+        );
+        assert_eq!(imported.as_ssz_bytes(), genesis_state.as_ssz_bytes());
+    }
+
+    #[test]
+    fn missing_database() {
+        let dir = tempdir().unwrap();
+
+        assert!(export_genesis::<E>(
+            &dir.path().join("chain_db"),
+            &dir.path().join("freezer_db"),
+            Format::Ssz,
+            &dir.path().join("genesis.ssz"),
+            E::default_spec(),
+            Logger::root(slog::Discard, slog::o!()),
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,93 @@
+//! Maintenance commands for the beacon node databases.
+//!
+//! These commands open the databases directly, so they must not be run whilst a beacon node is
+//! using the same data directory.
+
+pub mod compact;
+pub mod export_genesis;
+pub mod stats;
+
+use crate::get_data_dir;
+use clap::{App, ArgMatches};
+use client::ClientConfig;
+use environment::RuntimeContext;
+use std::fs;
+use std::path::{Path, PathBuf};
+use store::LevelDB;
+use types::EthSpec;
+
+pub const CMD: &str = "db";
+
+pub fn cli_app<'a, 'b>() -> App<'a, 'b> {
+    App::new(CMD)
+        .about(
+            "Maintenance commands for the beacon node databases. The beacon node must not be \
+             running.",
+        )
+        .subcommand(compact::cli_app())
+        .subcommand(export_genesis::cli_app())
+        .subcommand(stats::cli_app())
+}
+
+/// Runs a database command.
+///
+/// The database paths are read from `bn_matches`, the matches for the parent `beacon_node`
+/// command, so that `--datadir` and `--freezer-dir` apply as they do when running the node.
+pub fn cli_run<E: EthSpec>(
+    bn_matches: &ArgMatches,
+    matches: &ArgMatches,
+    context: RuntimeContext<E>,
+) -> Result<(), String> {
+    let (hot_path, freezer_path) = database_paths(bn_matches)?;
+    let databases = vec![("hot", hot_path.clone()), ("freezer", freezer_path.clone())];
+
+    match matches.subcommand() {
+        (compact::CMD, Some(_)) => compact::cli_run::<E>(&databases),
+        (export_genesis::CMD, Some(matches)) => {
+            export_genesis::cli_run(matches, &hot_path, &freezer_path, context)
+        }
+        (stats::CMD, Some(_)) => stats::cli_run::<E>(&databases),
+        (unknown, _) => Err(format!(
+            "{} does not have a {} command. See --help",
+            CMD, unknown
+        )),
+    }
+}
+
+/// Returns the paths of the hot and freezer databases.
+fn database_paths(bn_matches: &ArgMatches) -> Result<(PathBuf, PathBuf), String> {
+    let mut config = ClientConfig::default();
+    config.data_dir = get_data_dir(bn_matches);
+    config.freezer_db_path = bn_matches.value_of("freezer-dir").map(PathBuf::from);
+
+    let hot_path = config
+        .get_db_path()
+        .ok_or_else(|| "Unable to locate user home directory".to_string())?;
+    let freezer_path = config
+        .get_freezer_db_path()
+        .ok_or_else(|| "Unable to locate user home directory".to_string())?;
+
+    Ok((hot_path, freezer_path))
+}
+
+/// Opens the database at `path`, without creating it if it does not exist.
+fn open_existing<E: EthSpec>(path: &Path) -> Result<LevelDB<E>, String> {
+    if !path.exists() {
+        return Err(format!("Database does not exist: {:?}", path));
+    }
+
+    LevelDB::open(path).map_err(|e| format!("Unable to open database {:?}: {:?}", path, e))
+}
+
+/// Returns the total size in bytes of the files in the database directory at `path`.
+fn disk_size(path: &Path) -> Result<u64, String> {
+    fs::read_dir(path)
+        .map_err(|e| format!("Unable to read {:?}: {:?}", path, e))?
+        .map(|entry| {
+            entry
+                .and_then(|entry| entry.metadata())
+                .map(|metadata| metadata.len())
+                .map_err(|e| format!("Unable to read file in {:?}: {:?}", path, e))
+        })
+        .sum()
+}
@@ -0,0 +1,106 @@
+use super::open_existing;
+use clap::App;
+use std::path::PathBuf;
+use store::{DBColumn, KeyValueStore, LevelDB};
+use types::EthSpec;
+
+pub const CMD: &str = "stats";
+
+pub fn cli_app<'a, 'b>() -> App<'a, 'b> {
+    App::new(CMD).about("Lists the number of entries and their total size in each column.")
+}
+
+pub fn cli_run<E: EthSpec>(databases: &[(&str, PathBuf)]) -> Result<(), String> {
+    for (name, path) in databases {
+        let db = open_existing::<E>(path)?;
+
+        println!("{} database {:?}:", name, path);
+        for stats in column_stats(&db) {
+            println!(
+                "  {:<24} {:>10} entries {:>16} bytes",
+                format!("{:?}", stats.column),
+                stats.entries,
+                stats.key_bytes + stats.value_bytes
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// The number and total size of the entries in a database column.
+#[derive(Debug, PartialEq)]
+pub struct ColumnStats {
+    pub column: DBColumn,
+    pub entries: usize,
+    /// The total length of the keys, excluding the column prefix.
+    pub key_bytes: usize,
+    pub value_bytes: usize,
+}
+
+/// Returns the stats for every column of `db`, including empty columns.
+pub fn column_stats<E: EthSpec>(db: &LevelDB<E>) -> Vec<ColumnStats> {
+    DBColumn::ALL
+        .iter()
+        .map(|&column| {
+            db.iter_column(column).fold(
+                ColumnStats {
+                    column,
+                    entries: 0,
+                    key_bytes: 0,
+                    value_bytes: 0,
+                },
+                |mut stats, (key, value)| {
+                    stats.entries += 1;
+                    stats.key_bytes += key.len();
+                    stats.value_bytes += value.len();
+                    stats
+                },
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+    use types::MinimalEthSpec;
+
+    #[test]
+    fn stats_reflect_remaining_entries() {
+        let dir = tempdir().unwrap();
+        let db = LevelDB::<MinimalEthSpec>::open(dir.path()).unwrap();
+
+        let block: &str = DBColumn::BeaconBlock.into();
+        let state: &str = DBColumn::BeaconState.into();
+
+        db.put_bytes(block, b"aa", b"1").unwrap();
+        db.put_bytes(block, b"bb", b"22").unwrap();
+        db.put_bytes(block, b"cc", b"333").unwrap();
+        db.put_bytes(state, b"dd", b"4444").unwrap();
+
+        db.key_delete(block, b"bb").unwrap();
+        db.key_delete(state, b"dd").unwrap();
+
+        let stats = column_stats(&db);
+        assert_eq!(stats.len(), DBColumn::ALL.len());
+
+        for column_stats in stats {
+            let expected = match column_stats.column {
+                DBColumn::BeaconBlock => (2, 4, 4),
+                _ => (0, 0, 0),
+            };
+            assert_eq!(
+                (
+                    column_stats.entries,
+                    column_stats.key_bytes,
+                    column_stats.value_bytes
+                ),
+                expected,
+                "{:?}",
+                column_stats.column
+            );
+        }
+    }
+}
@@ -3,6 +3,7 @@ extern crate clap;
 
 mod cli;
 mod config;
+pub mod db;
 
 pub use beacon_chain;
 pub use cli::cli_app;
@@ -29,6 +29,8 @@ use types::*;
 
 /// 32-byte key for accessing the `split` of the freezer DB.
 pub const SPLIT_DB_KEY: &str = "FREEZERDBSPLITFREEZERDBSPLITFREE";
+/// 32-byte key for accessing the head block root in the hot DB.
+pub const HEAD_DB_KEY: &str = "HEADBLOCKROOTHEADBLOCKROOTHEADBL";
 
 /// On-disk database that stores finalized states efficiently.
 ///
@@ -186,6 +188,17 @@ impl<E: EthSpec, Hot: ItemStore<E>, Cold: ItemStore<E>> HotColdDB<E, Hot, Cold>
         self.hot_db.delete::<SignedBeaconBlock<E>>(block_root)
     }
 
+    /// Iterate backwards from the block with `block_root` to genesis, following parent roots.
+    ///
+    /// The first item is the block with `block_root` itself. If a block is missing from the store
+    /// the iterator yields `Error::BlockNotFound` and then stops.
+    pub fn iter_block_ancestors(
+        &self,
+        block_root: Hash256,
+    ) -> ParentRootBlockIterator<E, Hot, Cold> {
+        ParentRootBlockIterator::new(self, block_root)
+    }
+
     pub fn put_state_summary(
         &self,
         state_root: &Hash256,
@@ -249,6 +262,67 @@ impl<E: EthSpec, Hot: ItemStore<E>, Cold: ItemStore<E>> HotColdDB<E, Hot, Cold>
         Ok(())
     }
 
+    /// Delete the hot states with slots prior to the epoch containing `slot`, returning the number
+    /// of states deleted.
+    ///
+    /// The threshold is rounded down to an epoch boundary, because the hot states in an epoch are
+    /// reconstructed from the full state at its start. It is also clamped to the split slot, so
+    /// that unfinalized states are never deleted. The genesis state and the split state (the most
+    /// recently finalized state) are never deleted.
+    pub fn prune_states_below_slot(&self, slot: Slot) -> Result<usize, Error> {
+        let split = *self.split.read();
+        let threshold = std::cmp::min(
+            slot.epoch(E::slots_per_epoch())
+                .start_slot(E::slots_per_epoch()),
+            split.slot,
+        );
+
+        let mut ops = vec![];
+        for (key, value) in self.hot_db.iter_column(DBColumn::BeaconStateSummary) {
+            let state_root = Hash256::from_slice(&key);
+            let summary = HotStateSummary::from_store_bytes(&value)?;
+
+            if summary.slot < threshold
+                && summary.slot != self.spec.genesis_slot
+                && state_root != split.state_root
+            {
+                ops.push(StoreOp::DeleteState(state_root.into(), summary.slot));
+            }
+        }
+
+        let pruned = ops.len();
+        self.do_atomically(ops)?;
+
+        debug!(
+            self.log,
+            "Pruned hot states";
+            "below_slot" => threshold,
+            "count" => pruned
+        );
+
+        Ok(pruned)
+    }
+
+    /// Load the genesis state, from the freezer database if it has been migrated there, or
+    /// otherwise from the hot database.
+    ///
+    /// Returns `None` if no genesis state has been stored.
+    pub fn load_genesis_state(&self) -> Result<Option<BeaconState<E>>, Error> {
+        let genesis_slot = self.spec.genesis_slot;
+
+        if self.get_split_slot() > genesis_slot {
+            return self.load_cold_state_by_slot(genesis_slot).map(Some);
+        }
+
+        for (key, value) in self.hot_db.iter_column(DBColumn::BeaconStateSummary) {
+            if HotStateSummary::from_store_bytes(&value)?.slot == genesis_slot {
+                return self.load_hot_state(&Hash256::from_slice(&key));
+            }
+        }
+
+        Ok(None)
+    }
+
     pub fn forwards_block_roots_iterator(
         store: Arc<Self>,
         start_slot: Slot,
@@ -683,6 +757,19 @@ impl<E: EthSpec, Hot: ItemStore<E>, Cold: ItemStore<E>> HotColdDB<E, Hot, Cold>
         Ok(())
     }
 
+    /// Store the root of the head block, replacing any previously stored head.
+    pub fn put_head(&self, block_root: Hash256) -> Result<(), Error> {
+        let key = Hash256::from_slice(HEAD_DB_KEY.as_bytes());
+        self.hot_db.put(&key, &HeadPointer { block_root })
+    }
+
+    /// Load the root of the head block, if one has been stored.
+    pub fn get_head(&self) -> Result<Option<Hash256>, Error> {
+        let key = Hash256::from_slice(HEAD_DB_KEY.as_bytes());
+        let head: Option<HeadPointer> = self.hot_db.get(&key)?;
+        Ok(head.map(|head| head.block_root))
+    }
+
     /// Load the state root of a restore point.
     fn load_restore_point_hash(&self, restore_point_index: u64) -> Result<Hash256, Error> {
         let key = Self::restore_point_key(restore_point_index);
@@ -861,6 +948,26 @@ impl StoreItem for Split {
     }
 }
 
+/// Struct for storing the root of the head block in the database.
+#[derive(Debug, Clone, Copy, Encode, Decode)]
+struct HeadPointer {
+    block_root: Hash256,
+}
+
+impl StoreItem for HeadPointer {
+    fn db_column() -> DBColumn {
+        DBColumn::BeaconMeta
+    }
+
+    fn as_store_bytes(&self) -> Vec<u8> {
+        self.as_ssz_bytes()
+    }
+
+    fn from_store_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        Ok(Self::from_ssz_bytes(bytes)?)
+    }
+}
+
 /// Struct for summarising a state in the hot database.
 ///
 /// Allows full reconstruction by replaying blocks.
@@ -947,3 +1054,167 @@ impl StoreItem for RestorePointHash {
         Ok(Self::from_ssz_bytes(bytes)?)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sloggers::{null::NullLoggerBuilder, Build};
+    use tempfile::tempdir;
+
+    type E = MinimalEthSpec;
+
+    fn ephemeral_store(spec: &ChainSpec) -> HotColdDB<E, MemoryStore<E>, MemoryStore<E>> {
+        let log = NullLoggerBuilder.build().unwrap();
+        HotColdDB::open_ephemeral(StoreConfig::default(), spec.clone(), log).unwrap()
+    }
+
+    /// Stores a chain of `length` blocks, starting with a genesis block, and returns their roots.
+    fn store_chain(
+        store: &HotColdDB<E, MemoryStore<E>, MemoryStore<E>>,
+        length: u64,
+    ) -> Vec<Hash256> {
+        let mut parent_root = Hash256::zero();
+        (0..length)
+            .map(|slot| {
+                let mut block = SignedBeaconBlock {
+                    message: BeaconBlock::empty(&store.spec),
+                    signature: Signature::empty_signature(),
+                };
+                block.message.slot = Slot::new(slot);
+                block.message.parent_root = parent_root;
+
+                let block_root = block.canonical_root();
+                store.put_block(&block_root, block).unwrap();
+                parent_root = block_root;
+                block_root
+            })
+            .collect()
+    }
+
+    #[test]
+    fn iter_block_ancestors_reaches_genesis() {
+        let spec = E::default_spec();
+        let store = ephemeral_store(&spec);
+        let mut roots = store_chain(&store, 4);
+        let head_root = *roots.last().unwrap();
+
+        let ancestors = store
+            .iter_block_ancestors(head_root)
+            .map(|result| result.map(|(root, block)| (root, block.message.slot)))
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        roots.reverse();
+        assert_eq!(
+            ancestors,
+            roots
+                .into_iter()
+                .zip((0..4).rev().map(Slot::new))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn iter_block_ancestors_stops_at_missing_block() {
+        let spec = E::default_spec();
+        let store = ephemeral_store(&spec);
+        let roots = store_chain(&store, 4);
+        store.delete_block(&roots[1]).unwrap();
+
+        let mut ancestors = store.iter_block_ancestors(roots[3]);
+        assert_eq!(ancestors.next().unwrap().unwrap().0, roots[3]);
+        assert_eq!(ancestors.next().unwrap().unwrap().0, roots[2]);
+        assert!(matches!(
+            ancestors.next(),
+            Some(Err(Error::BlockNotFound(root))) if root == roots[1]
+        ));
+        assert!(ancestors.next().is_none());
+    }
+
+    #[test]
+    fn prune_states_below_slot() {
+        let spec = E::default_spec();
+        let store = ephemeral_store(&spec);
+
+        let epoch_boundaries = (0..5)
+            .map(|epoch| Epoch::new(epoch).start_slot(E::slots_per_epoch()))
+            .collect::<Vec<_>>();
+
+        for &slot in &epoch_boundaries {
+            let mut state = BeaconState::<E>::new(0, Eth1Data::default(), &spec);
+            state.slot = slot;
+            store
+                .put_state(&Hash256::from_low_u64_be(slot.as_u64()), &state)
+                .unwrap();
+        }
+
+        // Finalize the state at the start of epoch 2.
+        *store.split.write() = Split {
+            slot: epoch_boundaries[2],
+            state_root: Hash256::from_low_u64_be(epoch_boundaries[2].as_u64()),
+        };
+
+        // Pruning from the middle of epoch 2 only removes the states prior to epoch 2.
+        let prune_slot = epoch_boundaries[2] + 2;
+        assert_eq!(store.prune_states_below_slot(prune_slot).unwrap(), 1);
+
+        // Pruning above the split is clamped to the split slot, so the unfinalized states at the
+        // start of epochs 3 and 4 are kept.
+        assert_eq!(
+            store
+                .prune_states_below_slot(epoch_boundaries[4] + 1)
+                .unwrap(),
+            0
+        );
+
+        let remaining = epoch_boundaries
+            .iter()
+            .filter(|slot| {
+                let state_root = Hash256::from_low_u64_be(slot.as_u64());
+                store.load_hot_state_summary(&state_root).unwrap().is_some()
+            })
+            .copied()
+            .collect::<Vec<_>>();
+        assert_eq!(
+            remaining,
+            vec![
+                epoch_boundaries[0],
+                epoch_boundaries[2],
+                epoch_boundaries[3],
+                epoch_boundaries[4]
+            ]
+        );
+
+        // Pruning again removes nothing.
+        assert_eq!(store.prune_states_below_slot(prune_slot).unwrap(), 0);
+    }
+
+    #[test]
+    fn head_persists_across_restart() {
+        let spec = E::default_spec();
+        let dir = tempdir().unwrap();
+        let hot_path = dir.path().join("hot");
+        let cold_path = dir.path().join("cold");
+
+        let open = || {
+            HotColdDB::<E, _, _>::open(
+                &hot_path,
+                &cold_path,
+                StoreConfig::default(),
+                spec.clone(),
+                NullLoggerBuilder.build().unwrap(),
+            )
+            .unwrap()
+        };
+
+        let store = open();
+        assert_eq!(store.get_head().unwrap(), None);
+
+        store.put_head(Hash256::from_low_u64_be(1)).unwrap();
+        store.put_head(Hash256::from_low_u64_be(2)).unwrap();
+        drop(store);
+
+        let store = open();
+        assert_eq!(store.get_head().unwrap(), Some(Hash256::from_low_u64_be(2)));
+    }
+}
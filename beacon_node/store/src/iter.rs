@@ -253,6 +253,8 @@ impl<'a, E: EthSpec, Hot: ItemStore<E>, Cold: ItemStore<E>>
             Ok(None)
         } else {
             let block_root = self.next_block_root;
+            // Stop after any error, rather than repeatedly failing to load the same block.
+            self.next_block_root = Hash256::zero();
             let block = self
                 .store
                 .get_block(&block_root)?
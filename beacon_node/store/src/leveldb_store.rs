@@ -2,13 +2,19 @@ use super::*;
 use crate::metrics;
 use db_key::Key;
 use leveldb::database::batch::{Batch, Writebatch};
+use leveldb::database::compaction::Compaction;
+use leveldb::database::iterator::Iterable;
 use leveldb::database::kv::KV;
+use leveldb::database::snapshots::Snapshots;
 use leveldb::database::Database;
 use leveldb::error::Error as LevelDBError;
 use leveldb::options::{Options, ReadOptions, WriteOptions};
 use std::marker::PhantomData;
 use std::path::Path;
 
+/// The number of key-value pairs written to the destination in each batch when copying a database.
+const COPY_BATCH_SIZE: usize = 1_024;
+
 /// A wrapped leveldb database.
 pub struct LevelDB<E: EthSpec> {
     db: Database<BytesKey>,
@@ -30,6 +36,63 @@ impl<E: EthSpec> LevelDB<E> {
         })
     }
 
+    /// Writes a copy of the database to a new database at `dest`.
+    ///
+    /// The copy is read from a LevelDB snapshot, so it is consistent with the state of the
+    /// database when this function is called, even if there are concurrent writes.
+    ///
+    /// Returns an error if `dest` already exists.
+    pub fn snapshot(&self, dest: &Path) -> Result<(), Error> {
+        if dest.exists() {
+            return Err(Error::DBError {
+                message: format!("Snapshot destination already exists: {:?}", dest),
+            });
+        }
+
+        let copy = Self::open(dest)?;
+        let snapshot = self.db.snapshot();
+
+        let mut batch = Writebatch::new();
+        let mut batch_len = 0;
+
+        for (key, value) in snapshot.iter(self.read_options()) {
+            batch.put(key, &value);
+            batch_len += 1;
+
+            if batch_len == COPY_BATCH_SIZE {
+                copy.db.write(copy.write_options(), &batch)?;
+                batch = Writebatch::new();
+                batch_len = 0;
+            }
+        }
+
+        copy.db.write(copy.write_options(), &batch)?;
+
+        Ok(())
+    }
+
+    /// Restores the database snapshot at `src`, produced by `Self::snapshot`, to a new database at
+    /// `dest`. The snapshot at `src` is not modified.
+    ///
+    /// Returns an error if `src` does not exist or `dest` already exists.
+    pub fn restore(src: &Path, dest: &Path) -> Result<(), Error> {
+        if !src.exists() {
+            return Err(Error::DBError {
+                message: format!("Snapshot does not exist: {:?}", src),
+            });
+        }
+
+        Self::open(src)?.snapshot(dest)
+    }
+
+    /// Compacts the entire key range of the database, discarding deleted and overwritten values.
+    pub fn compact(&self) {
+        // All column names are ASCII, so every key sorts between the empty key and `0xff`.
+        let start = BytesKey::from_vec(vec![]);
+        let end = BytesKey::from_vec(vec![0xff]);
+        self.db.compact(&start, &end);
+    }
+
     fn read_options(&self) -> ReadOptions<BytesKey> {
         ReadOptions::new()
     }
@@ -114,6 +177,26 @@ impl<E: EthSpec> KeyValueStore<E> for LevelDB<E> {
         self.db.write(self.write_options(), &leveldb_batch)?;
         Ok(())
     }
+
+    /// Iterates over all key-value pairs in `column`, in key order.
+    ///
+    /// The iterator reads from an implicit LevelDB snapshot, so it is unaffected by writes made
+    /// after it is created.
+    fn iter_column(&self, column: DBColumn) -> ColumnIter {
+        let column: &'static str = column.into();
+        let prefix = get_key_for_col(column, &[]);
+
+        Box::new(
+            self.db
+                .iter(self.read_options())
+                .skip_while({
+                    let prefix = prefix.clone();
+                    move |(key, _)| key.key < prefix
+                })
+                .take_while(move |(key, _)| key.key.starts_with(&prefix))
+                .map(move |(key, value)| (key.key[column.len()..].to_vec(), value)),
+        )
+    }
 }
 
 impl<E: EthSpec> ItemStore<E> for LevelDB<E> {}
@@ -146,3 +229,72 @@ impl From<LevelDBError> for Error {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+    use types::MinimalEthSpec;
+
+    type Store = LevelDB<MinimalEthSpec>;
+
+    #[test]
+    fn snapshot_and_restore() {
+        let dir = tempdir().unwrap();
+        let col: &str = DBColumn::BeaconBlock.into();
+
+        let db = Store::open(&dir.path().join("db")).unwrap();
+        db.put_bytes(col, b"a", b"before").unwrap();
+        db.put_bytes(col, b"b", b"deleted").unwrap();
+
+        let snapshot_path = dir.path().join("snapshot");
+        db.snapshot(&snapshot_path).unwrap();
+        assert!(db.snapshot(&snapshot_path).is_err());
+
+        db.put_bytes(col, b"a", b"after").unwrap();
+        db.key_delete(col, b"b").unwrap();
+        db.put_bytes(col, b"c", b"added").unwrap();
+
+        let restored_path = dir.path().join("restored");
+        Store::restore(&snapshot_path, &restored_path).unwrap();
+
+        for path in &[snapshot_path, restored_path] {
+            let copy = Store::open(path).unwrap();
+            assert_eq!(copy.get_bytes(col, b"a").unwrap(), Some(b"before".to_vec()));
+            assert_eq!(
+                copy.get_bytes(col, b"b").unwrap(),
+                Some(b"deleted".to_vec())
+            );
+            assert_eq!(copy.get_bytes(col, b"c").unwrap(), None);
+        }
+
+        assert_eq!(db.get_bytes(col, b"a").unwrap(), Some(b"after".to_vec()));
+    }
+
+    #[test]
+    fn iter_column() {
+        let dir = tempdir().unwrap();
+        let db = Store::open(dir.path()).unwrap();
+
+        let block: &str = DBColumn::BeaconBlock.into();
+        let state: &str = DBColumn::BeaconState.into();
+
+        db.put_bytes(block, b"b", b"2").unwrap();
+        db.put_bytes(block, b"a", b"1").unwrap();
+        db.put_bytes(block, b"c", b"3").unwrap();
+        db.put_bytes(state, b"a", b"state").unwrap();
+        db.key_delete(block, b"c").unwrap();
+
+        assert_eq!(
+            db.iter_column(DBColumn::BeaconBlock).collect::<Vec<_>>(),
+            vec![
+                (b"a".to_vec(), b"1".to_vec()),
+                (b"b".to_vec(), b"2".to_vec())
+            ]
+        );
+        assert_eq!(db.iter_column(DBColumn::OpPool).count(), 0);
+
+        db.compact();
+        assert_eq!(db.iter_column(DBColumn::BeaconBlock).count(), 2);
+    }
+}
@@ -36,6 +36,9 @@ pub use impls::beacon_state::StorageContainer as BeaconStateStorageContainer;
 pub use metrics::scrape_for_metrics;
 pub use types::*;
 
+/// An iterator over the keys (without their column prefix) and values in a database column.
+pub type ColumnIter<'a> = Box<dyn Iterator<Item = (Vec<u8>, Vec<u8>)> + 'a>;
+
 pub trait KeyValueStore<E: EthSpec>: Sync + Send + Sized + 'static {
     /// Retrieve some bytes in `column` with `key`.
     fn get_bytes(&self, column: &str, key: &[u8]) -> Result<Option<Vec<u8>>, Error>;
@@ -51,6 +54,9 @@ pub trait KeyValueStore<E: EthSpec>: Sync + Send + Sized + 'static {
 
     /// Execute either all of the operations in `batch` or none at all, returning an error.
     fn do_atomically(&self, batch: Vec<KeyValueStoreOp>) -> Result<(), Error>;
+
+    /// Iterate over all key-value pairs in `column`, in key order.
+    fn iter_column(&self, column: DBColumn) -> ColumnIter;
 }
 
 pub fn get_key_for_col(column: &str, key: &[u8]) -> Vec<u8> {
@@ -136,6 +142,26 @@ pub enum DBColumn {
     DhtEnrs,
 }
 
+impl DBColumn {
+    /// Every column, in declaration order.
+    pub const ALL: [DBColumn; 14] = [
+        DBColumn::BeaconMeta,
+        DBColumn::BeaconBlock,
+        DBColumn::BeaconState,
+        DBColumn::BeaconChain,
+        DBColumn::OpPool,
+        DBColumn::Eth1Cache,
+        DBColumn::ForkChoice,
+        DBColumn::BeaconRestorePoint,
+        DBColumn::BeaconStateSummary,
+        DBColumn::BeaconBlockRoots,
+        DBColumn::BeaconStateRoots,
+        DBColumn::BeaconHistoricalRoots,
+        DBColumn::BeaconRandaoMixes,
+        DBColumn::DhtEnrs,
+    ];
+}
+
 impl Into<&'static str> for DBColumn {
     /// Returns a `&str` that can be used for keying a key-value data base.
     fn into(self) -> &'static str {
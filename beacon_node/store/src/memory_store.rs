@@ -1,4 +1,4 @@
-use super::{Error, ItemStore, KeyValueStore, KeyValueStoreOp};
+use super::{ColumnIter, DBColumn, Error, ItemStore, KeyValueStore, KeyValueStoreOp};
 use parking_lot::RwLock;
 use std::collections::HashMap;
 use std::marker::PhantomData;
@@ -78,6 +78,22 @@ impl<E: EthSpec> KeyValueStore<E> for MemoryStore<E> {
         }
         Ok(())
     }
+
+    /// Iterate over a copy of the key-value pairs in `column`, in key order.
+    fn iter_column(&self, column: DBColumn) -> ColumnIter {
+        let column: &'static str = column.into();
+
+        let mut pairs = self
+            .db
+            .read()
+            .iter()
+            .filter(|(key, _)| key.starts_with(column.as_bytes()))
+            .map(|(key, value)| (key[column.len()..].to_vec(), value.clone()))
+            .collect::<Vec<_>>();
+        pairs.sort();
+
+        Box::new(pairs.into_iter())
+    }
 }
 
 impl<E: EthSpec> ItemStore<E> for MemoryStore<E> {}
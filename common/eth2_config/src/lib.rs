@@ -51,4 +51,19 @@ mod tests {
         let _ =
             toml::to_string(&Eth2Config::default()).expect("Should serde encode default config");
     }
+
+    #[test]
+    fn toml_round_trip() {
+        for config in vec![
+            Eth2Config::mainnet(),
+            Eth2Config::minimal(),
+            Eth2Config::interop(),
+        ] {
+            let encoded = toml::to_string(&config).expect("Should serde encode config");
+            let decoded: Eth2Config = toml::from_str(&encoded).expect("Should serde decode config");
+
+            assert_eq!(decoded.spec_constants, config.spec_constants);
+            assert_eq!(decoded.spec, config.spec);
+        }
+    }
 }
@@ -4,10 +4,14 @@
 //! Presently, this is only used for testing but it _could_ become a user-facing library.
 
 use eth2_config::Eth2Config;
-use reqwest::{Client, ClientBuilder, Response, StatusCode};
+use reqwest::{Client, ClientBuilder, RequestBuilder, Response, StatusCode};
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use ssz::Encode;
 use std::marker::PhantomData;
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
 use std::time::Duration;
 use types::{
     Attestation, AttestationData, AttesterSlashing, BeaconBlock, BeaconState, CommitteeIndex,
@@ -20,8 +24,8 @@ pub use operation_pool::PersistedOperationPool;
 pub use proto_array::core::ProtoArray;
 pub use rest_types::{
     CanonicalHeadResponse, Committee, HeadBeaconBlock, Health, IndividualVotesRequest,
-    IndividualVotesResponse, SyncingResponse, ValidatorDutiesRequest, ValidatorDutyBytes,
-    ValidatorRequest, ValidatorResponse, ValidatorSubscription,
+    IndividualVotesResponse, ProposerDuty, SyncingResponse, ValidatorDutiesRequest,
+    ValidatorDutyBytes, ValidatorRequest, ValidatorResponse, ValidatorSubscription, VoteCount,
 };
 
 // Setting a long timeout for debug ensures that crypto-heavy operations can still succeed.
@@ -49,6 +53,18 @@ impl<E: EthSpec> RemoteBeaconNode<E> {
                 .map_err(|e| format!("Unable to create http client: {:?}", e))?,
         })
     }
+
+    /// Connects to the first of `http_endpoints` that is reachable, failing over to the next
+    /// endpoint whenever the current one cannot be reached.
+    pub fn new_with_fallbacks(
+        http_endpoints: Vec<String>,
+        timeout: Duration,
+    ) -> Result<Self, String> {
+        Ok(Self {
+            http: HttpClient::new_with_fallbacks(http_endpoints, timeout)
+                .map_err(|e| format!("Unable to create http client: {:?}", e))?,
+        })
+    }
 }
 
 #[derive(Debug)]
@@ -65,10 +81,27 @@ pub enum Error {
     InvalidInput,
 }
 
+impl Error {
+    /// Returns `true` if the error is likely to be resolved by retrying the same request later
+    /// (e.g., a timeout or a server-side error), rather than being caused by the request itself.
+    pub fn is_transient(&self) -> bool {
+        match self {
+            Error::ReqwestError(e) => e.is_timeout() || e.is_request(),
+            Error::DidNotSucceed { status, .. } => status.is_server_error(),
+            Error::UrlParseError(_) | Error::SerdeJsonError(_) | Error::InvalidInput => false,
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct HttpClient<E> {
     client: Client,
-    url: Url,
+    /// The base URLs of the nodes, in order of preference.
+    urls: Arc<Vec<Url>>,
+    /// The index of the node in `urls` that is currently in use.
+    ///
+    /// Shared between all clones, so a fail over is remembered by every user of the client.
+    current: Arc<AtomicUsize>,
     timeout: Duration,
     _phantom: PhantomData<E>,
 }
@@ -76,17 +109,38 @@ pub struct HttpClient<E> {
 impl<E: EthSpec> HttpClient<E> {
     /// Creates a new instance (without connecting to the node).
     pub fn new(server_url: String, timeout: Duration) -> Result<Self, Error> {
+        Self::new_with_fallbacks(vec![server_url], timeout)
+    }
+
+    /// Creates a new instance (without connecting to any node) which sends requests to the first
+    /// of `server_urls`, moving on to the next URL whenever the current node cannot be reached.
+    pub fn new_with_fallbacks(server_urls: Vec<String>, timeout: Duration) -> Result<Self, Error> {
+        let urls = server_urls
+            .iter()
+            .map(|url| Url::parse(url))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if urls.is_empty() {
+            return Err(Error::InvalidInput);
+        }
+
         Ok(Self {
             client: ClientBuilder::new()
                 .timeout(timeout)
                 .build()
                 .expect("should build from static configuration"),
-            url: Url::parse(&server_url)?,
+            urls: Arc::new(urls),
+            current: Arc::new(AtomicUsize::new(0)),
             timeout: Duration::from_secs(15),
             _phantom: PhantomData,
         })
     }
 
+    /// Returns the base URL of the node that is currently in use.
+    pub fn current_url(&self) -> &Url {
+        &self.urls[self.current.load(Ordering::Relaxed)]
+    }
+
     pub fn beacon(&self) -> Beacon<E> {
         Beacon(self.clone())
     }
@@ -103,6 +157,10 @@ impl<E: EthSpec> HttpClient<E> {
         Node(self.clone())
     }
 
+    pub fn network(&self) -> Network<E> {
+        Network(self.clone())
+    }
+
     pub fn advanced(&self) -> Advanced<E> {
         Advanced(self.clone())
     }
@@ -112,16 +170,73 @@ impl<E: EthSpec> HttpClient<E> {
     }
 
     fn url(&self, path: &str) -> Result<Url, Error> {
-        self.url.join(path).map_err(|e| e.into())
+        self.current_url().join(path).map_err(|e| e.into())
+    }
+
+    /// Sends the request produced by `build` for `url`.
+    ///
+    /// If the node cannot be reached and the request is `idempotent`, the client fails over to the
+    /// next node and the request is re-sent to it, until each node has been tried once.
+    ///
+    /// Requests that are not idempotent (e.g., publishing a block) never fail over, since a node
+    /// that timed out may still have acted on the request. A later idempotent request will fail
+    /// over instead.
+    async fn send<F>(&self, mut url: Url, idempotent: bool, build: F) -> Result<Response, Error>
+    where
+        F: Fn(&Client, &Url) -> RequestBuilder,
+    {
+        let mut attempts = 1;
+
+        loop {
+            match build(&self.client, &url).send().await {
+                Err(e) if idempotent && is_connection_error(&e) && attempts < self.urls.len() => {
+                    // Find the (most specific) base URL of the node that failed.
+                    let failed = match self
+                        .urls
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, base)| url.as_str().starts_with(base.as_str()))
+                        .max_by_key(|(_, base)| base.as_str().len())
+                        .map(|(i, _)| i)
+                    {
+                        Some(failed) => failed,
+                        None => return Err(Error::ReqwestError(e)),
+                    };
+
+                    // Only fail over if another request has not done so already.
+                    let _ = self.current.compare_exchange(
+                        failed,
+                        next_url_index(failed, self.urls.len()),
+                        Ordering::Relaxed,
+                        Ordering::Relaxed,
+                    );
+
+                    url = match rebase_url(&url, &self.urls[failed], self.current_url()) {
+                        Some(url) => url,
+                        None => return Err(Error::ReqwestError(e)),
+                    };
+                    attempts += 1;
+                }
+                result => return result.map_err(Error::from),
+            }
+        }
+    }
+
+    /// Returns the Prometheus metrics of the node, in the text exposition format.
+    pub async fn get_metrics(&self) -> Result<String, Error> {
+        let url = self.url("metrics")?;
+        let response = self
+            .send(url, true, |client, url| client.get(&url.to_string()))
+            .await?;
+        let success = error_for_status(response).await.map_err(Error::from)?;
+        success.text().await.map_err(Error::from)
     }
 
     pub async fn json_post<T: Serialize>(&self, url: Url, body: T) -> Result<Response, Error> {
-        self.client
-            .post(&url.to_string())
-            .json(&body)
-            .send()
-            .await
-            .map_err(Error::from)
+        self.send(url, false, |client, url| {
+            client.post(&url.to_string()).json(&body)
+        })
+        .await
     }
 
     pub async fn json_get<T: DeserializeOwned>(
@@ -134,17 +249,35 @@ impl<E: EthSpec> HttpClient<E> {
         });
 
         let response = self
-            .client
-            .get(&url.to_string())
-            .send()
-            .await
-            .map_err(Error::from)?;
+            .send(url, true, |client, url| client.get(&url.to_string()))
+            .await?;
 
         let success = error_for_status(response).await.map_err(Error::from)?;
         success.json::<T>().await.map_err(Error::from)
     }
 }
 
+/// Returns `true` if `e` indicates that the node could not be reached (or did not respond in
+/// time), in which case it is worth trying another node.
+fn is_connection_error(e: &reqwest::Error) -> bool {
+    e.is_request() || e.is_timeout()
+}
+
+/// Returns the index of the URL to fail over to after the URL at `failed` could not be reached.
+fn next_url_index(failed: usize, url_count: usize) -> usize {
+    (failed + 1) % url_count
+}
+
+/// Moves `url`, which must start with the base URL `from`, onto the base URL `to`, retaining the
+/// path and query.
+fn rebase_url(url: &Url, from: &Url, to: &Url) -> Option<Url> {
+    if url.as_str().starts_with(from.as_str()) {
+        to.join(&url.as_str()[from.as_str().len()..]).ok()
+    } else {
+        None
+    }
+}
+
 /// Returns an `Error` (with a description) if the `response` was not a 200-type success response.
 ///
 /// Distinct from `Response::error_for_status` because it includes the body of the response as
@@ -290,6 +423,15 @@ impl<E: EthSpec> Validator<E> {
         success.json().await.map_err(Error::from)
     }
 
+    /// Returns the block proposer for each slot in the given epoch.
+    pub async fn get_proposers(&self, epoch: Epoch) -> Result<Vec<ProposerDuty>, Error> {
+        let query_params = vec![("epoch".into(), format!("{}", epoch.as_u64()))];
+
+        let client = self.0.clone();
+        let url = self.url("proposers")?;
+        client.json_get(url, query_params).await
+    }
+
     /// Posts a block to the beacon node, expecting it to verify it and publish it to the network.
     pub async fn publish_block(&self, block: SignedBeaconBlock<E>) -> Result<PublishStatus, Error> {
         let client = self.0.clone();
@@ -309,22 +451,26 @@ impl<E: EthSpec> Validator<E> {
     }
 
     /// Requests a new (unsigned) block from the beacon node.
+    ///
+    /// If `graffiti` is `None`, the beacon node will use its default graffiti.
     pub async fn produce_block(
         &self,
         slot: Slot,
         randao_reveal: Signature,
+        graffiti: Option<[u8; 32]>,
     ) -> Result<BeaconBlock<E>, Error> {
+        let mut query_params = vec![
+            ("slot".into(), format!("{}", slot.as_u64())),
+            ("randao_reveal".into(), as_ssz_hex_string(&randao_reveal)),
+        ];
+
+        if let Some(graffiti) = graffiti {
+            query_params.push(("graffiti".into(), as_ssz_hex_string(&graffiti)));
+        }
+
         let client = self.0.clone();
         let url = self.url("block")?;
-        client
-            .json_get::<BeaconBlock<E>>(
-                url,
-                vec![
-                    ("slot".into(), format!("{}", slot.as_u64())),
-                    ("randao_reveal".into(), as_ssz_hex_string(&randao_reveal)),
-                ],
-            )
-            .await
+        client.json_get::<BeaconBlock<E>>(url, query_params).await
     }
 
     /// Subscribes a list of validators to particular slots for attestation production/publication.
@@ -625,6 +771,26 @@ impl<E: EthSpec> Node<E> {
     }
 }
 
+/// Provides the functions on the `/network` endpoint of the node.
+#[derive(Clone)]
+pub struct Network<E>(HttpClient<E>);
+
+impl<E: EthSpec> Network<E> {
+    fn url(&self, path: &str) -> Result<Url, Error> {
+        self.0
+            .url("network/")
+            .and_then(move |url| url.join(path).map_err(Error::from))
+            .map_err(Into::into)
+    }
+
+    /// Returns the number of peers connected to the node.
+    pub async fn get_peer_count(&self) -> Result<usize, Error> {
+        let client = self.0.clone();
+        let url = self.url("peer_count")?;
+        client.json_get(url, vec![]).await
+    }
+}
+
 /// Provides the functions on the `/advanced` endpoint of the node.
 #[derive(Clone)]
 pub struct Advanced<E>(HttpClient<E>);
@@ -680,7 +846,7 @@ impl<E: EthSpec> Consensus<E> {
     }
 
     /// Gets a `VoteCount` for the given `epoch`.
-    pub async fn get_vote_count(&self, epoch: Epoch) -> Result<IndividualVotesResponse, Error> {
+    pub async fn get_vote_count(&self, epoch: Epoch) -> Result<VoteCount, Error> {
         let client = self.0.clone();
         let query_params = vec![("epoch".into(), format!("{}", epoch.as_u64()))];
         let url = self.url("vote_count")?;
@@ -727,3 +893,118 @@ impl From<serde_json::Error> for Error {
         Error::SerdeJsonError(e)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+    use types::MinimalEthSpec;
+
+    type E = MinimalEthSpec;
+
+    #[test]
+    fn fail_over_to_next_url() {
+        assert_eq!(next_url_index(0, 3), 1);
+        assert_eq!(next_url_index(1, 3), 2);
+        assert_eq!(next_url_index(2, 3), 0);
+        assert_eq!(next_url_index(0, 1), 0);
+    }
+
+    #[test]
+    fn rebase() {
+        let first = Url::parse("http://localhost:5052/").unwrap();
+        let second = Url::parse("http://10.0.0.2:5052/").unwrap();
+        let url = first.join("validator/duties?epoch=2").unwrap();
+
+        assert_eq!(
+            rebase_url(&url, &first, &second),
+            Some(Url::parse("http://10.0.0.2:5052/validator/duties?epoch=2").unwrap())
+        );
+        assert_eq!(rebase_url(&url, &second, &first), None);
+    }
+
+    #[test]
+    fn transient_errors() {
+        let did_not_succeed = |status| Error::DidNotSucceed {
+            status,
+            body: String::new(),
+        };
+
+        assert!(did_not_succeed(StatusCode::INTERNAL_SERVER_ERROR).is_transient());
+        assert!(did_not_succeed(StatusCode::SERVICE_UNAVAILABLE).is_transient());
+        assert!(!did_not_succeed(StatusCode::BAD_REQUEST).is_transient());
+        assert!(!did_not_succeed(StatusCode::NOT_FOUND).is_transient());
+
+        // An invalid request is never transient.
+        let builder_error = Client::new()
+            .get("not a url")
+            .build()
+            .expect_err("should not build a request without a valid url");
+        assert!(!Error::ReqwestError(builder_error).is_transient());
+
+        assert!(!Error::UrlParseError(Url::parse("not a url").unwrap_err()).is_transient());
+        assert!(
+            !Error::SerdeJsonError(serde_json::from_str::<u64>("").unwrap_err()).is_transient()
+        );
+        assert!(!Error::InvalidInput.is_transient());
+    }
+
+    /// Returns the URL of a local port that nothing is listening on.
+    fn unreachable_url() -> String {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        format!("http://{}/", listener.local_addr().unwrap())
+    }
+
+    /// Starts a node that responds to every request with `body`, returning its URL and the number
+    /// of requests it has received.
+    async fn mock_node(body: &'static str) -> (String, Arc<AtomicUsize>) {
+        let mut listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/", listener.local_addr().unwrap());
+        let requests = Arc::new(AtomicUsize::new(0));
+        let counter = requests.clone();
+
+        tokio::spawn(async move {
+            while let Ok((mut socket, _)) = listener.accept().await {
+                counter.fetch_add(1, Ordering::Relaxed);
+
+                let mut buf = [0; 4096];
+                let _ = socket.read(&mut buf).await;
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+                let _ = socket.write_all(response.as_bytes()).await;
+            }
+        });
+
+        (url, requests)
+    }
+
+    #[tokio::test]
+    async fn fails_over_from_unreachable_node() {
+        let unreachable = unreachable_url();
+        let (live, requests) = mock_node("ok").await;
+        let client = HttpClient::<E>::new_with_fallbacks(
+            vec![unreachable.clone(), live.clone()],
+            Duration::from_secs(5),
+        )
+        .unwrap();
+
+        // A `POST` is not idempotent, so it is not re-sent to the next node.
+        let url = client.url("beacon/block").unwrap();
+        assert!(client.json_post(url, ()).await.is_err());
+        assert_eq!(client.current_url().as_str(), unreachable);
+        assert_eq!(requests.load(Ordering::Relaxed), 0);
+
+        // A `GET` fails over to the next node, which is used from then on.
+        assert_eq!(client.get_metrics().await.unwrap(), "ok");
+        assert_eq!(client.current_url().as_str(), live);
+        assert_eq!(requests.load(Ordering::Relaxed), 1);
+
+        let url = client.url("beacon/block").unwrap();
+        assert!(client.json_post(url, ()).await.is_ok());
+        assert_eq!(requests.load(Ordering::Relaxed), 2);
+    }
+}
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 use ssz_derive::{Decode, Encode};
-use state_processing::per_epoch_processing::ValidatorStatus;
+use state_processing::per_epoch_processing::{TotalBalances, ValidatorStatus};
 use types::{Epoch, PublicKeyBytes};
 
 #[derive(PartialEq, Debug, Serialize, Deserialize, Clone, Encode, Decode)]
@@ -64,3 +64,41 @@ pub struct IndividualVotesResponse {
     /// Voting statistics for the validator, if they voted in the given epoch.
     pub vote: Option<IndividualVote>,
 }
+
+/// The results of validators voting during an epoch.
+///
+/// Provides information about the current and previous epochs.
+#[derive(Serialize, Deserialize, Encode, Decode)]
+pub struct VoteCount {
+    /// The total effective balance of all active validators during the _current_ epoch.
+    pub current_epoch_active_gwei: u64,
+    /// The total effective balance of all active validators during the _previous_ epoch.
+    pub previous_epoch_active_gwei: u64,
+    /// The total effective balance of all validators who attested during the _current_ epoch.
+    pub current_epoch_attesting_gwei: u64,
+    /// The total effective balance of all validators who attested during the _current_ epoch and
+    /// agreed with the state about the beacon block at the first slot of the _current_ epoch.
+    pub current_epoch_target_attesting_gwei: u64,
+    /// The total effective balance of all validators who attested during the _previous_ epoch.
+    pub previous_epoch_attesting_gwei: u64,
+    /// The total effective balance of all validators who attested during the _previous_ epoch and
+    /// agreed with the state about the beacon block at the first slot of the _previous_ epoch.
+    pub previous_epoch_target_attesting_gwei: u64,
+    /// The total effective balance of all validators who attested during the _previous_ epoch and
+    /// agreed with the state about the beacon block at the time of attestation.
+    pub previous_epoch_head_attesting_gwei: u64,
+}
+
+impl Into<VoteCount> for TotalBalances {
+    fn into(self) -> VoteCount {
+        VoteCount {
+            current_epoch_active_gwei: self.current_epoch(),
+            previous_epoch_active_gwei: self.previous_epoch(),
+            current_epoch_attesting_gwei: self.current_epoch_attesters(),
+            current_epoch_target_attesting_gwei: self.current_epoch_target_attesters(),
+            previous_epoch_attesting_gwei: self.previous_epoch_attesters(),
+            previous_epoch_target_attesting_gwei: self.previous_epoch_target_attesters(),
+            previous_epoch_head_attesting_gwei: self.previous_epoch_head_attesters(),
+        }
+    }
+}
@@ -13,9 +13,9 @@ pub use beacon::{
 };
 
 pub use validator::{
-    ValidatorDutiesRequest, ValidatorDuty, ValidatorDutyBytes, ValidatorSubscription,
+    ProposerDuty, ValidatorDutiesRequest, ValidatorDuty, ValidatorDutyBytes, ValidatorSubscription,
 };
 
-pub use consensus::{IndividualVote, IndividualVotesRequest, IndividualVotesResponse};
+pub use consensus::{IndividualVote, IndividualVotesRequest, IndividualVotesResponse, VoteCount};
 
 pub use node::{Health, SyncingResponse, SyncingStatus};
@@ -57,6 +57,15 @@ pub struct ValidatorDutiesRequest {
     pub pubkeys: Vec<PublicKeyBytes>,
 }
 
+/// The proposer of a single slot, as returned by the proposer schedule endpoint.
+#[derive(PartialEq, Debug, Serialize, Deserialize, Clone, Encode, Decode)]
+pub struct ProposerDuty {
+    /// The slot in which the block should be proposed.
+    pub slot: Slot,
+    /// The index of the proposer in `state.validators`.
+    pub validator_index: u64,
+}
+
 /// A validator subscription, created when a validator subscribes to a slot to perform optional aggregation
 /// duties.
 #[derive(PartialEq, Debug, Serialize, Deserialize, Clone, Encode, Decode)]
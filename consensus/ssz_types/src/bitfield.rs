@@ -95,8 +95,8 @@ pub struct Bitfield<T> {
 }
 
 impl<N: Unsigned + Clone> Bitfield<Variable<N>> {
-    /// Instantiate with capacity for `num_bits` boolean values. The length cannot be grown or
-    /// shrunk after instantiation.
+    /// Instantiate with capacity for `num_bits` boolean values. After instantiation the length can
+    /// only be changed via `extend_from` and `truncate`.
     ///
     /// All bits are initialized to `false`.
     ///
@@ -198,6 +198,18 @@ impl<N: Unsigned + Clone> Bitfield<Variable<N>> {
         }
     }
 
+    /// Instantiates a new instance with one bit per entry in `bools`, where bit `i` is set iff
+    /// `bools[i] == true`.
+    ///
+    /// Returns `Err` if `bools.len() > N`.
+    pub fn from_bool_vec(bools: &[bool]) -> Result<Self, Error> {
+        let mut bitfield = Self::with_capacity(bools.len())?;
+        for (i, value) in bools.iter().enumerate() {
+            bitfield.set(i, *value)?;
+        }
+        Ok(bitfield)
+    }
+
     /// Compute the intersection of two BitLists of potentially different lengths.
     ///
     /// Return a new BitList with length equal to the shorter of the two inputs.
@@ -225,6 +237,70 @@ impl<N: Unsigned + Clone> Bitfield<Variable<N>> {
         }
         result
     }
+
+    /// Appends the bits of `other` to the end of `self`, such that bit `i` of `other` becomes bit
+    /// `self.len() + i` of the result.
+    ///
+    /// Returns `Err` (leaving `self` unchanged) if the combined length would exceed `N`.
+    pub fn extend_from(&mut self, other: &Self) -> Result<(), Error> {
+        let old_len = self.len();
+        let new_len = old_len + other.len();
+
+        if new_len > Self::max_len() {
+            return Err(Error::OutOfBounds {
+                i: new_len,
+                len: Self::max_len(),
+            });
+        }
+
+        self.bytes.resize(bytes_for_bit_len(new_len), 0);
+        self.len = new_len;
+
+        let byte_offset = old_len / 8;
+        let bit_offset = old_len % 8;
+
+        // Shift each byte of `other` into place. When `old_len` is not byte-aligned the high bits
+        // of each byte spill over into the following byte. Since `other` has no set bits beyond
+        // its length, no set bit can land beyond `new_len`.
+        for (i, byte) in other.bytes.iter().enumerate() {
+            if *byte == 0 {
+                continue;
+            }
+
+            self.bytes[byte_offset + i] |= byte << bit_offset;
+
+            if bit_offset > 0 {
+                let carry = byte >> (8 - bit_offset);
+                if carry > 0 {
+                    self.bytes[byte_offset + i + 1] |= carry;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Shortens `self` to `new_len` bits, discarding all bits at or above `new_len`.
+    ///
+    /// Has no effect if `new_len >= self.len()`.
+    pub fn truncate(&mut self, new_len: usize) {
+        if new_len >= self.len() {
+            return;
+        }
+
+        self.bytes.truncate(bytes_for_bit_len(new_len));
+        self.len = new_len;
+
+        // Clear any bits in the final byte that are now beyond the length.
+        if new_len % 8 != 0 {
+            let mask = u8::max_value() >> (8 - new_len % 8);
+            if let Some(last) = self.bytes.last_mut() {
+                *last &= mask;
+            }
+        } else if new_len == 0 {
+            self.bytes = vec![0];
+        }
+    }
 }
 
 impl<N: Unsigned + Clone> Bitfield<Fixed<N>> {
@@ -300,6 +376,41 @@ impl<T: BitfieldBehaviour> Bitfield<T> {
         }
     }
 
+    /// Sets every bit in the range `start..end` to `value`.
+    ///
+    /// Bytes which fall entirely within the range are written in a single operation, rather than
+    /// bit-by-bit.
+    ///
+    /// Returns `Err` (leaving `self` unchanged) if `start > end` or `end > self.len()`.
+    ///
+    /// The length is not extended to `end`. The length of a bitfield is part of its SSZ encoding
+    /// (e.g., it is the committee size for aggregation bits) and is fixed by the `Bitfield`
+    /// constructors, so a range beyond it is an error. Use `with_capacity` to create a bitfield of
+    /// the required length first.
+    pub fn set_range(&mut self, start: usize, end: usize, value: bool) -> Result<(), Error> {
+        if end > self.len {
+            return Err(Error::OutOfBounds {
+                i: end,
+                len: self.len,
+            });
+        } else if start > end {
+            return Err(Error::OutOfBounds { i: start, len: end });
+        }
+
+        let mut i = start;
+        while i < end {
+            if i % 8 == 0 && end - i >= 8 {
+                self.bytes[i / 8] = if value { u8::max_value() } else { 0 };
+                i += 8;
+            } else {
+                self.set(i, value)?;
+                i += 1;
+            }
+        }
+
+        Ok(())
+    }
+
     /// Returns the value of the `i`'th bit.
     ///
     /// Returns `Error` if `i` is out-of-bounds of `self`.
@@ -397,6 +508,11 @@ impl<T: BitfieldBehaviour> Bitfield<T> {
         }
     }
 
+    /// Returns a `Vec` with one `bool` per bit of `self`, starting at the lowest index.
+    pub fn to_bool_vec(&self) -> Vec<bool> {
+        self.iter().collect()
+    }
+
     /// Returns true if no bits are set.
     pub fn is_zero(&self) -> bool {
         self.bytes.iter().all(|byte| *byte == 0)
@@ -1183,6 +1299,167 @@ mod bitlist {
         assert!(b.shift_up(17).is_err());
     }
 
+    #[test]
+    fn extend_from() {
+        let mut a = BitList1024::with_capacity(5).unwrap();
+        a.set(0, true).unwrap();
+        a.set(4, true).unwrap();
+
+        let mut b = BitList1024::with_capacity(7).unwrap();
+        b.set(0, true).unwrap();
+        b.set(3, true).unwrap();
+        b.set(6, true).unwrap();
+
+        a.extend_from(&b).unwrap();
+
+        assert_eq!(a.len(), 12);
+        assert_eq!(a.as_slice().len(), 2);
+        for i in 0..12 {
+            assert_eq!(
+                a.get(i).unwrap(),
+                [0, 4, 5, 8, 11].contains(&i),
+                "bit {}",
+                i
+            );
+        }
+        assert_round_trip(a);
+    }
+
+    #[test]
+    fn extend_from_every_offset() {
+        for i in 0..17 {
+            for j in 0..17 {
+                let mut a = BitList1024::with_capacity(i).unwrap();
+                let mut b = BitList1024::with_capacity(j).unwrap();
+                for k in 0..j {
+                    b.set(k, true).unwrap();
+                }
+
+                a.extend_from(&b).unwrap();
+
+                assert_eq!(a.len(), i + j);
+                assert_eq!(a.num_set_bits(), j);
+                assert_eq!(
+                    a.iter().collect::<Vec<_>>(),
+                    (0..i + j).map(|k| k >= i).collect::<Vec<_>>()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn truncate() {
+        let mut a =
+            BitList1024::from_raw_bytes(vec![0b1111_0101, 0b1011_0011, 0b0000_0001], 17).unwrap();
+
+        a.truncate(20);
+        assert_eq!(a.len(), 17);
+
+        a.truncate(11);
+        assert_eq!(a.len(), 11);
+        assert_eq!(a.as_slice(), &[0b1111_0101, 0b0000_0011]);
+        assert_eq!(a.num_set_bits(), 8);
+        assert!(a.get(11).is_err());
+        assert_round_trip(a.clone());
+
+        a.truncate(8);
+        assert_eq!(a.len(), 8);
+        assert_eq!(a.as_slice(), &[0b1111_0101]);
+
+        a.truncate(3);
+        assert_eq!(a.as_slice(), &[0b0000_0101]);
+        assert_eq!(a.highest_set_bit(), Some(2));
+
+        a.truncate(0);
+        assert!(a.is_empty());
+        assert_eq!(a.as_slice(), &[0]);
+        assert_round_trip(a);
+    }
+
+    #[test]
+    fn truncate_then_extend() {
+        let mut a = BitList1024::with_capacity(16).unwrap();
+        for i in 0..16 {
+            a.set(i, true).unwrap();
+        }
+        a.truncate(5);
+        a.extend_from(&BitList1024::with_capacity(6).unwrap())
+            .unwrap();
+
+        assert_eq!(a.len(), 11);
+        assert_eq!(
+            a.iter().collect::<Vec<_>>(),
+            (0..11).map(|i| i < 5).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn extend_from_exceeds_max_len() {
+        let mut a = BitList8::with_capacity(5).unwrap();
+        a.set(1, true).unwrap();
+        let b = BitList8::with_capacity(4).unwrap();
+
+        assert!(a.extend_from(&b).is_err());
+        assert_eq!(a.len(), 5);
+        assert_eq!(a.get(1), Ok(true));
+
+        let c = BitList8::with_capacity(3).unwrap();
+        assert!(a.extend_from(&c).is_ok());
+        assert_eq!(a.len(), 8);
+    }
+
+    #[test]
+    fn set_range() {
+        let mut a = BitList1024::with_capacity(30).unwrap();
+
+        // Within a single byte.
+        a.set_range(1, 4, true).unwrap();
+        // Spanning a partial byte, a full byte and another partial byte.
+        a.set_range(6, 21, true).unwrap();
+        assert_eq!(a.num_set_bits(), 3 + 15);
+        for i in 0..30 {
+            assert_eq!(
+                a.get(i).unwrap(),
+                (1..4).contains(&i) || (6..21).contains(&i),
+                "bit {}",
+                i
+            );
+        }
+
+        // Clearing a range which is exactly one byte.
+        a.set_range(8, 16, false).unwrap();
+        assert_eq!(a.as_slice()[1], 0);
+        assert_eq!(a.num_set_bits(), 3 + 7);
+
+        // An empty range is a no-op.
+        a.set_range(25, 25, true).unwrap();
+        assert_eq!(a.num_set_bits(), 3 + 7);
+
+        // Setting up to the very last bit leaves no excess bits set.
+        a.set_range(0, 30, true).unwrap();
+        assert_eq!(a.num_set_bits(), 30);
+        assert_round_trip(a);
+    }
+
+    #[test]
+    fn set_range_out_of_bounds() {
+        let mut a = BitList1024::with_capacity(30).unwrap();
+        a.set_range(0, 30, true).unwrap();
+
+        assert_eq!(
+            a.set_range(0, 31, false),
+            Err(Error::OutOfBounds { i: 31, len: 30 })
+        );
+        assert_eq!(
+            a.set_range(5, 4, false),
+            Err(Error::OutOfBounds { i: 5, len: 4 })
+        );
+
+        // The length is not extended and no bits are changed.
+        assert_eq!(a.len(), 30);
+        assert_eq!(a.num_set_bits(), 30);
+    }
+
     #[test]
     fn num_set_bits() {
         let a = BitList1024::from_raw_bytes(vec![0b1100, 0b0001], 16).unwrap();
@@ -1204,6 +1481,25 @@ mod bitlist {
         );
     }
 
+    #[test]
+    fn bool_vec_round_trip() {
+        let mut b = BitList1024::with_capacity(19).unwrap();
+        for i in &[0, 3, 7, 8, 13, 18] {
+            b.set(*i, true).unwrap();
+        }
+
+        let bools = b.to_bool_vec();
+        assert_eq!(bools.len(), 19);
+        assert_eq!(bools.iter().filter(|b| **b).count(), 6);
+        assert_eq!(BitList1024::from_bool_vec(&bools).unwrap(), b);
+
+        assert_eq!(
+            BitList1024::from_bool_vec(&[]).unwrap(),
+            BitList1024::with_capacity(0).unwrap()
+        );
+        assert!(BitList8::from_bool_vec(&[true; 9]).is_err());
+    }
+
     #[test]
     fn ssz_bytes_len() {
         for i in 1..64 {
@@ -8,6 +8,7 @@ pub mod genesis;
 pub mod per_block_processing;
 pub mod per_epoch_processing;
 pub mod per_slot_processing;
+pub mod replay_blocks;
 pub mod test_utils;
 pub mod verify_operation;
 
@@ -21,4 +22,5 @@ pub use per_block_processing::{
 };
 pub use per_epoch_processing::{errors::EpochProcessingError, per_epoch_processing};
 pub use per_slot_processing::{per_slot_processing, Error as SlotProcessingError};
+pub use replay_blocks::{replay_blocks, TransitionError};
 pub use verify_operation::{SigVerifiedOp, VerifyOperation};
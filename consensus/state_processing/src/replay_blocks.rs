@@ -0,0 +1,120 @@
+use crate::per_block_processing::{
+    errors::BlockProcessingError, per_block_processing, BlockSignatureStrategy,
+};
+use crate::per_slot_processing::{per_slot_processing, Error as SlotProcessingError};
+use types::{BeaconState, ChainSpec, EthSpec, SignedBeaconBlock};
+
+#[derive(Debug, PartialEq)]
+pub enum TransitionError {
+    SlotProcessing(SlotProcessingError),
+    BlockProcessing(BlockProcessingError),
+}
+
+/// Applies each of `blocks` in turn to a copy of `genesis`, advancing through any skipped slots,
+/// and returns the resulting state.
+///
+/// Useful for finding where a recorded chain diverges. If a block cannot be applied, returns its
+/// index in `blocks` along with the error.
+pub fn replay_blocks<T: EthSpec>(
+    genesis: &BeaconState<T>,
+    blocks: &[SignedBeaconBlock<T>],
+    spec: &ChainSpec,
+) -> Result<BeaconState<T>, (usize, TransitionError)> {
+    let mut state = genesis.clone();
+
+    for (i, block) in blocks.iter().enumerate() {
+        while state.slot < block.slot() {
+            per_slot_processing(&mut state, None, spec)
+                .map_err(|e| (i, TransitionError::SlotProcessing(e)))?;
+        }
+
+        per_block_processing(
+            &mut state,
+            block,
+            None,
+            BlockSignatureStrategy::VerifyIndividual,
+            spec,
+        )
+        .map_err(|e| (i, TransitionError::BlockProcessing(e)))?;
+    }
+
+    Ok(state)
+}
+
+#[cfg(all(test, not(feature = "fake_crypto")))]
+mod tests {
+    use super::*;
+    use types::test_utils::{TestingBeaconBlockBuilder, TestingBeaconStateBuilder};
+    use types::{Hash256, Keypair, MinimalEthSpec};
+
+    type E = MinimalEthSpec;
+
+    /// Produces a block at each of the `num_blocks` slots following `genesis`.
+    fn build_chain(
+        genesis: &BeaconState<E>,
+        keypairs: &[Keypair],
+        num_blocks: usize,
+        spec: &ChainSpec,
+    ) -> (Vec<SignedBeaconBlock<E>>, BeaconState<E>) {
+        let mut state = genesis.clone();
+        let mut blocks = vec![];
+
+        for _ in 0..num_blocks {
+            per_slot_processing(&mut state, None, spec).unwrap();
+            state.build_all_caches(spec).unwrap();
+
+            let proposer_index = state.get_beacon_proposer_index(state.slot, spec).unwrap();
+            let sk = &keypairs[proposer_index].sk;
+
+            let mut builder = TestingBeaconBlockBuilder::new(spec);
+            builder.set_slot(state.slot);
+            builder.set_proposer_index(proposer_index as u64);
+            builder.set_parent_root(state.latest_block_header.canonical_root());
+            builder.set_randao_reveal(sk, &state.fork, state.genesis_validators_root, spec);
+            let block = builder.build(sk, &state.fork, state.genesis_validators_root, spec);
+
+            per_block_processing(
+                &mut state,
+                &block,
+                None,
+                BlockSignatureStrategy::VerifyIndividual,
+                spec,
+            )
+            .unwrap();
+            blocks.push(block);
+        }
+
+        (blocks, state)
+    }
+
+    #[test]
+    fn replays_valid_blocks() {
+        let spec = E::default_spec();
+        let (genesis, keypairs) =
+            TestingBeaconStateBuilder::from_deterministic_keypairs(64, &spec).build();
+        let (blocks, mut expected) = build_chain(&genesis, &keypairs, 4, &spec);
+
+        let mut replayed = replay_blocks(&genesis, &blocks, &spec).unwrap();
+
+        assert_eq!(replayed.slot, expected.slot);
+        assert_eq!(
+            replayed.update_tree_hash_cache().unwrap(),
+            expected.update_tree_hash_cache().unwrap()
+        );
+    }
+
+    #[test]
+    fn stops_at_corrupted_block() {
+        let spec = E::default_spec();
+        let (genesis, keypairs) =
+            TestingBeaconStateBuilder::from_deterministic_keypairs(64, &spec).build();
+        let (mut blocks, _) = build_chain(&genesis, &keypairs, 4, &spec);
+
+        blocks[2].message.parent_root = Hash256::zero();
+
+        assert!(matches!(
+            replay_blocks(&genesis, &blocks, &spec),
+            Err((2, TransitionError::BlockProcessing(_)))
+        ));
+    }
+}
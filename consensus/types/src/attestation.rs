@@ -14,6 +14,13 @@ pub enum Error {
     SszTypesError(ssz_types::Error),
     AlreadySigned(usize),
     SubnetCountIsZero(ArithError),
+    /// There were no attestations to aggregate.
+    NoAttestations,
+    /// The attestations to be aggregated do not all have the same data.
+    DataMismatch,
+    /// Two of the attestations to be aggregated were signed by the same validator, who would be
+    /// counted twice in the aggregate.
+    SignersOverlap,
 }
 
 /// Details an attestation that can be slashable.
@@ -47,6 +54,27 @@ impl<T: EthSpec> Attestation<T> {
         self.signature.add_aggregate(&other.signature);
     }
 
+    /// Aggregates all of `attestations` into a single attestation.
+    ///
+    /// The attestations must all have the same data and their aggregation bitfields must be
+    /// pairwise disjoint. Returns `SignersOverlap` rather than aggregating a signer twice.
+    pub fn aggregate_all(attestations: &[Self]) -> Result<Self, Error> {
+        let (first, rest) = attestations.split_first().ok_or(Error::NoAttestations)?;
+
+        let mut aggregate = first.clone();
+        for attestation in rest {
+            if attestation.data != aggregate.data {
+                return Err(Error::DataMismatch);
+            }
+            if !aggregate.signers_disjoint_from(attestation) {
+                return Err(Error::SignersOverlap);
+            }
+            aggregate.aggregate(attestation);
+        }
+
+        Ok(aggregate)
+    }
+
     /// Signs `self`, setting the `committee_position`'th bit of `aggregation_bits` to `true`.
     ///
     /// Returns an `AlreadySigned` error if the `committee_position`'th bit is already `true`.
@@ -91,4 +119,70 @@ mod tests {
     use crate::*;
 
     ssz_and_tree_hash_tests!(Attestation<MainnetEthSpec>);
+
+    /// Returns an attestation with empty data, signed by the validators at `positions` in a
+    /// committee of four.
+    fn signed_attestation(positions: &[usize]) -> Attestation<MainnetEthSpec> {
+        let spec = MainnetEthSpec::default_spec();
+        let mut attestation = Attestation {
+            aggregation_bits: BitList::with_capacity(4).unwrap(),
+            data: AttestationData::default(),
+            signature: AggregateSignature::new(),
+        };
+
+        for position in positions {
+            attestation
+                .sign(
+                    &Keypair::random().sk,
+                    *position,
+                    &Fork::default(),
+                    Hash256::zero(),
+                    &spec,
+                )
+                .unwrap();
+        }
+
+        attestation
+    }
+
+    #[test]
+    fn aggregate_disjoint_attestations() {
+        let attestations = vec![
+            signed_attestation(&[0]),
+            signed_attestation(&[1, 3]),
+            signed_attestation(&[]),
+        ];
+
+        let aggregate = Attestation::aggregate_all(&attestations).unwrap();
+
+        let signers = (0..4)
+            .map(|i| aggregate.aggregation_bits.get(i).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(signers, vec![true, true, false, true]);
+    }
+
+    #[test]
+    fn aggregate_overlapping_attestations() {
+        let attestations = vec![signed_attestation(&[0, 2]), signed_attestation(&[1, 2])];
+
+        assert_eq!(
+            Attestation::aggregate_all(&attestations),
+            Err(AttestationError::SignersOverlap)
+        );
+    }
+
+    #[test]
+    fn aggregate_mismatched_attestations() {
+        let mut other = signed_attestation(&[1]);
+        other.data.index = 1;
+
+        assert_eq!(
+            Attestation::aggregate_all(&[signed_attestation(&[0]), other]),
+            Err(AttestationError::DataMismatch)
+        );
+        assert_eq!(
+            Attestation::<MainnetEthSpec>::aggregate_all(&[]),
+            Err(AttestationError::NoAttestations)
+        );
+    }
 }
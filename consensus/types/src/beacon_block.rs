@@ -51,6 +51,18 @@ impl<T: EthSpec> BeaconBlock<T> {
         }
     }
 
+    /// Returns the genesis block for the genesis state with root `state_root`.
+    ///
+    /// The block is at the genesis slot, has a zero parent root and an empty body.
+    ///
+    /// Spec v0.12.1
+    pub fn genesis(state_root: Hash256, spec: &ChainSpec) -> Self {
+        BeaconBlock {
+            state_root,
+            ..BeaconBlock::empty(spec)
+        }
+    }
+
     /// Return a block where the block has the max possible operations.
     pub fn full(spec: &ChainSpec) -> BeaconBlock<T> {
         let header = BeaconBlockHeader {
@@ -213,4 +225,21 @@ mod tests {
     use super::*;
 
     ssz_and_tree_hash_tests!(BeaconBlock<MainnetEthSpec>);
+
+    #[test]
+    fn genesis() {
+        let spec = MainnetEthSpec::default_spec();
+        let state_root = Hash256::repeat_byte(42);
+
+        let block = BeaconBlock::<MainnetEthSpec>::genesis(state_root, &spec);
+
+        assert_eq!(block.slot, Slot::new(0));
+        assert_eq!(block.parent_root, Hash256::zero());
+        assert_eq!(block.state_root, state_root);
+        assert_eq!(block.body, BeaconBlock::<MainnetEthSpec>::empty(&spec).body);
+        assert_eq!(
+            block.tree_hash_root(),
+            BeaconBlock::<MainnetEthSpec>::genesis(state_root, &spec).tree_hash_root()
+        );
+    }
 }
@@ -8,6 +8,21 @@ pub struct BeaconCommittee<'a> {
 }
 
 impl<'a> BeaconCommittee<'a> {
+    /// Returns the number of validators in the committee.
+    pub fn committee_size(&self) -> usize {
+        self.committee.len()
+    }
+
+    /// Returns `true` if the committee has no members.
+    pub fn is_empty(&self) -> bool {
+        self.committee.is_empty()
+    }
+
+    /// Returns `true` if the validator with `validator_index` is a member of the committee.
+    pub fn contains_validator(&self, validator_index: usize) -> bool {
+        self.committee.contains(&validator_index)
+    }
+
     pub fn into_owned(self) -> OwnedBeaconCommittee {
         OwnedBeaconCommittee {
             slot: self.slot,
@@ -24,3 +39,59 @@ pub struct OwnedBeaconCommittee {
     pub index: CommitteeIndex,
     pub committee: Vec<usize>,
 }
+
+impl OwnedBeaconCommittee {
+    /// Returns the number of validators in the committee.
+    pub fn committee_size(&self) -> usize {
+        self.committee.len()
+    }
+
+    /// Returns `true` if the committee has no members.
+    pub fn is_empty(&self) -> bool {
+        self.committee.is_empty()
+    }
+
+    /// Returns `true` if the validator with `validator_index` is a member of the committee.
+    pub fn contains_validator(&self, validator_index: usize) -> bool {
+        self.committee.contains(&validator_index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn populated_committee() {
+        let committee = BeaconCommittee {
+            slot: Slot::new(1),
+            index: 2,
+            committee: &[4, 8, 15],
+        };
+
+        assert_eq!(committee.committee_size(), 3);
+        assert!(!committee.is_empty());
+        assert!(committee.contains_validator(8));
+        assert!(!committee.contains_validator(16));
+
+        let owned = committee.into_owned();
+        assert_eq!(owned.committee_size(), 3);
+        assert!(!owned.is_empty());
+        assert!(owned.contains_validator(15));
+        assert!(!owned.contains_validator(16));
+    }
+
+    #[test]
+    fn empty_committee() {
+        let committee = BeaconCommittee::default();
+
+        assert_eq!(committee.committee_size(), 0);
+        assert!(committee.is_empty());
+        assert!(!committee.contains_validator(0));
+
+        let owned = committee.into_owned();
+        assert_eq!(owned.committee_size(), 0);
+        assert!(owned.is_empty());
+        assert!(!owned.contains_validator(0));
+    }
+}
@@ -1,6 +1,7 @@
 #![cfg(test)]
 use super::*;
 use crate::{test_utils::*, *};
+use safe_arith::ArithError;
 
 #[test]
 fn default_values() {
@@ -15,6 +16,34 @@ fn default_values() {
     assert!(cache.get_beacon_committees_at_slot(Slot::new(0)).is_err());
 }
 
+#[test]
+fn active_validator_indices_respect_activation_and_exit() {
+    let epoch = Epoch::new(10);
+
+    let validator = |activation_epoch: u64, exit_epoch: u64| Validator {
+        activation_epoch: Epoch::new(activation_epoch),
+        exit_epoch: Epoch::new(exit_epoch),
+        ..Validator::default()
+    };
+
+    let validators = vec![
+        // Exited before `epoch`.
+        validator(2, 5),
+        // Active across `epoch`.
+        validator(5, 15),
+        // Activates after `epoch`.
+        validator(11, 20),
+        // Activates exactly at `epoch`.
+        validator(10, 20),
+        // Exits exactly at `epoch`.
+        validator(5, 10),
+        // Never activated.
+        Validator::default(),
+    ];
+
+    assert_eq!(get_active_validator_indices(&validators, epoch), vec![1, 3]);
+}
+
 fn new_state<T: EthSpec>(validator_count: usize, slot: Slot) -> BeaconState<T> {
     let spec = &T::default_spec();
 
@@ -39,6 +68,20 @@ fn fails_without_validators() {
     );
 }
 
+#[test]
+fn fails_with_zero_target_committee_size() {
+    let state = new_state::<MinimalEthSpec>(16, Slot::new(0));
+    let spec = &ChainSpec {
+        target_committee_size: 0,
+        ..MinimalEthSpec::default_spec()
+    };
+
+    assert_eq!(
+        CommitteeCache::initialized(&state, state.current_epoch(), spec),
+        Err(BeaconStateError::ArithError(ArithError::DivisionByZero))
+    );
+}
+
 #[test]
 fn initializes_with_the_right_epoch() {
     let state = new_state::<MinimalEthSpec>(16, Slot::new(0));
@@ -386,6 +386,56 @@ impl Default for ChainSpec {
     }
 }
 
+/// Returns the `(field, a_value, b_value)` of every field which differs between `a` and `b`, in
+/// declaration order.
+///
+/// Useful when debugging interop failures, where mismatched constants are a common cause.
+///
+/// Fields are compared by their serialized values, so fields which are not serialized (i.e.,
+/// `far_future_epoch`) are not compared.
+///
+/// Returns an error if either spec cannot be serialized to a YAML mapping.
+pub fn diff_specs(a: &ChainSpec, b: &ChainSpec) -> Result<Vec<(String, String, String)>, String> {
+    let fields = |spec: &ChainSpec| match serde_yaml::to_value(spec) {
+        Ok(serde_yaml::Value::Mapping(fields)) => Ok(fields),
+        Ok(other) => Err(format!(
+            "ChainSpec should serialize to a mapping, got {:?}",
+            other
+        )),
+        Err(e) => Err(format!("Unable to serialize ChainSpec: {:?}", e)),
+    };
+    let a_fields = fields(a)?;
+    let b_fields = fields(b)?;
+
+    let differences = a_fields
+        .iter()
+        .filter_map(|(field, a_value)| {
+            let b_value = b_fields.get(field)?;
+            if a_value == b_value {
+                None
+            } else {
+                Some((
+                    yaml_to_string(field),
+                    yaml_to_string(a_value),
+                    yaml_to_string(b_value),
+                ))
+            }
+        })
+        .collect();
+
+    Ok(differences)
+}
+
+/// Formats a YAML value on a single line, without the document marker.
+fn yaml_to_string(value: &serde_yaml::Value) -> String {
+    match value {
+        serde_yaml::Value::String(s) => s.clone(),
+        other => serde_yaml::to_string(other)
+            .map(|s| s.trim_start_matches("---").trim().replace('\n', " "))
+            .unwrap_or_else(|e| format!("<{:?}>", e)),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -395,6 +445,64 @@ mod tests {
         let _ = ChainSpec::mainnet();
     }
 
+    #[test]
+    fn test_diff_specs() {
+        let mainnet = ChainSpec::mainnet();
+        let minimal = ChainSpec::minimal();
+
+        assert!(diff_specs(&mainnet, &mainnet).unwrap().is_empty());
+
+        let diff = diff_specs(&mainnet, &minimal).unwrap();
+        let fields = diff
+            .iter()
+            .map(|(field, _, _)| field.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            fields,
+            vec![
+                "max_committees_per_slot",
+                "target_committee_size",
+                "shuffle_round_count",
+                "min_genesis_active_validator_count",
+                "genesis_fork_version",
+                "genesis_delay",
+                "milliseconds_per_slot",
+                "shard_committee_period",
+                "safe_slots_to_update_justified",
+                "eth1_follow_distance",
+                "network_id",
+            ]
+        );
+        assert!(diff.contains(&(
+            "target_committee_size".to_string(),
+            "128".to_string(),
+            "4".to_string()
+        )));
+    }
+
+    #[test]
+    fn test_interop_spec() {
+        let interop = ChainSpec::interop();
+
+        assert_eq!(InteropEthSpec::slots_per_epoch(), 8);
+        assert_eq!(InteropEthSpec::default_spec(), interop);
+
+        let fields = diff_specs(&ChainSpec::mainnet(), &interop)
+            .unwrap()
+            .into_iter()
+            .map(|(field, _, _)| field)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            fields,
+            vec![
+                "target_committee_size",
+                "shuffle_round_count",
+                "milliseconds_per_slot",
+                "network_id",
+            ]
+        );
+    }
+
     fn test_domain(domain_type: Domain, raw_domain: u32, spec: &ChainSpec) {
         let previous_version = [0, 0, 0, 1];
         let current_version = [0, 0, 0, 2];
@@ -4,6 +4,7 @@ use crate::*;
 use bls::{PublicKeyBytes, SignatureBytes};
 use serde_derive::{Deserialize, Serialize};
 use ssz_derive::{Decode, Encode};
+use std::convert::TryInto;
 use test_random_derive::TestRandom;
 use tree_hash_derive::TreeHash;
 
@@ -40,6 +41,36 @@ impl DepositData {
 
         SignatureBytes::from(Signature::new(msg.as_bytes(), secret_key))
     }
+
+    /// Returns `true` if `self.amount` is at least the minimum deposit amount and is a whole
+    /// multiple of the effective balance increment.
+    ///
+    /// The spec accepts any amount, so this is for validating deposits which we create (e.g., at
+    /// genesis), where a misaligned amount indicates a mistake.
+    pub fn is_valid_amount(&self, spec: &ChainSpec) -> bool {
+        self.amount >= spec.min_deposit_amount
+            && self.amount.checked_rem(spec.effective_balance_increment) == Some(0)
+    }
+
+    /// Returns `true` if `self.signature` is a valid signature of the deposit message by
+    /// `self.pubkey`, proving possession of the corresponding secret key.
+    ///
+    /// Returns `false` if either the pubkey or signature bytes are invalid.
+    pub fn verify_proof_of_possession(&self, spec: &ChainSpec) -> bool {
+        let pubkey: PublicKey = match (&self.pubkey).try_into() {
+            Ok(pubkey) => pubkey,
+            Err(_) => return false,
+        };
+        let signature: Signature = match (&self.signature).try_into() {
+            Ok(signature) => signature,
+            Err(_) => return false,
+        };
+
+        let domain = spec.get_deposit_domain();
+        let msg = self.as_deposit_message().signing_root(domain);
+
+        signature.verify(msg.as_bytes(), &pubkey)
+    }
 }
 
 #[cfg(test)]
@@ -47,4 +78,48 @@ mod tests {
     use super::*;
 
     ssz_and_tree_hash_tests!(DepositData);
+
+    fn signed_deposit_data(keypair: &Keypair, spec: &ChainSpec) -> DepositData {
+        let mut deposit_data = DepositData {
+            pubkey: keypair.pk.clone().into(),
+            withdrawal_credentials: Hash256::from_low_u64_be(42),
+            amount: spec.max_effective_balance,
+            signature: Signature::empty_signature().into(),
+        };
+        deposit_data.signature = deposit_data.create_signature(&keypair.sk, spec);
+        deposit_data
+    }
+
+    #[test]
+    fn is_valid_amount() {
+        let spec = MainnetEthSpec::default_spec();
+        let mut deposit_data = signed_deposit_data(&Keypair::random(), &spec);
+
+        deposit_data.amount = spec.min_deposit_amount;
+        assert!(deposit_data.is_valid_amount(&spec));
+
+        deposit_data.amount = spec.min_deposit_amount.saturating_sub(1);
+        assert!(!deposit_data.is_valid_amount(&spec));
+
+        // 32.5 ETH.
+        deposit_data.amount = 32_500_000_000;
+        assert!(!deposit_data.is_valid_amount(&spec));
+    }
+
+    #[test]
+    fn verify_proof_of_possession() {
+        let spec = MainnetEthSpec::default_spec();
+        let keypair = Keypair::random();
+
+        let deposit_data = signed_deposit_data(&keypair, &spec);
+        assert!(deposit_data.verify_proof_of_possession(&spec));
+
+        let mut swapped_pubkey = deposit_data.clone();
+        swapped_pubkey.pubkey = Keypair::random().pk.into();
+        assert!(!swapped_pubkey.verify_proof_of_possession(&spec));
+
+        let mut empty_signature = deposit_data;
+        empty_signature.signature = Signature::empty_signature().into();
+        assert!(!empty_signature.verify_proof_of_possession(&spec));
+    }
 }
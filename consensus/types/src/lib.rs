@@ -33,6 +33,7 @@ pub mod historical_batch;
 pub mod indexed_attestation;
 pub mod pending_attestation;
 pub mod proposer_slashing;
+pub mod registry_diff;
 pub mod relative_epoch;
 pub mod selection_proof;
 pub mod signed_aggregate_and_proof;
@@ -64,7 +65,7 @@ pub use crate::beacon_block_body::BeaconBlockBody;
 pub use crate::beacon_block_header::BeaconBlockHeader;
 pub use crate::beacon_committee::{BeaconCommittee, OwnedBeaconCommittee};
 pub use crate::beacon_state::{BeaconTreeHashCache, Error as BeaconStateError, *};
-pub use crate::chain_spec::{ChainSpec, Domain, YamlConfig};
+pub use crate::chain_spec::{diff_specs, ChainSpec, Domain, YamlConfig};
 pub use crate::checkpoint::Checkpoint;
 pub use crate::deposit::{Deposit, DEPOSIT_TREE_DEPTH};
 pub use crate::deposit_data::DepositData;
@@ -78,6 +79,7 @@ pub use crate::historical_batch::HistoricalBatch;
 pub use crate::indexed_attestation::IndexedAttestation;
 pub use crate::pending_attestation::PendingAttestation;
 pub use crate::proposer_slashing::ProposerSlashing;
+pub use crate::registry_diff::{registry_diff, RegistryDiff};
 pub use crate::relative_epoch::{Error as RelativeEpochError, RelativeEpoch};
 pub use crate::selection_proof::SelectionProof;
 pub use crate::signed_aggregate_and_proof::SignedAggregateAndProof;
@@ -0,0 +1,115 @@
+use crate::*;
+
+/// The changes to the status of validators in the registry between two `BeaconState`s.
+///
+/// Each list contains the indices of validators, in ascending order.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct RegistryDiff {
+    /// Validators that are active at the current epoch of the new state, but were not active at
+    /// the current epoch of the old state (or were not present in it at all).
+    pub activated: Vec<usize>,
+    /// Validators that are exited at the current epoch of the new state, but were not exited at
+    /// the current epoch of the old state (or were not present in it at all).
+    pub exited: Vec<usize>,
+    /// Validators that are slashed in the new state, but were not slashed in the old state (or
+    /// were not present in it at all).
+    pub slashed: Vec<usize>,
+}
+
+impl RegistryDiff {
+    /// Returns `true` if no validator changed status.
+    pub fn is_empty(&self) -> bool {
+        self.activated.is_empty() && self.exited.is_empty() && self.slashed.is_empty()
+    }
+}
+
+/// Returns the validators which were activated, exited or slashed between `old` and `new`.
+///
+/// The activation and exit status of each validator is determined at the current epoch of the
+/// respective state, so `new` is expected to be a descendant of `old`.
+pub fn registry_diff<T: EthSpec>(old: &BeaconState<T>, new: &BeaconState<T>) -> RegistryDiff {
+    let old_epoch = old.current_epoch();
+    let new_epoch = new.current_epoch();
+
+    let mut diff = RegistryDiff::default();
+
+    for (i, validator) in new.validators.iter().enumerate() {
+        let previous = old.validators.get(i);
+
+        if validator.is_active_at(new_epoch)
+            && !previous.map_or(false, |v| v.is_active_at(old_epoch))
+        {
+            diff.activated.push(i);
+        }
+
+        if validator.is_exited_at(new_epoch)
+            && !previous.map_or(false, |v| v.is_exited_at(old_epoch))
+        {
+            diff.exited.push(i);
+        }
+
+        if validator.slashed && !previous.map_or(false, |v| v.slashed) {
+            diff.slashed.push(i);
+        }
+    }
+
+    diff
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::TestingBeaconStateBuilder;
+
+    type E = MinimalEthSpec;
+
+    fn build_state(validator_count: usize, spec: &ChainSpec) -> BeaconState<E> {
+        let builder: TestingBeaconStateBuilder<E> =
+            TestingBeaconStateBuilder::from_deterministic_keypairs(validator_count, spec);
+        let (state, _keypairs) = builder.build();
+        state
+    }
+
+    #[test]
+    fn identical_states() {
+        let spec = E::default_spec();
+        let state = build_state(8, &spec);
+
+        assert!(registry_diff(&state, &state).is_empty());
+    }
+
+    #[test]
+    fn activations_exits_and_slashings() {
+        let spec = E::default_spec();
+        let mut old = build_state(8, &spec);
+        old.validators[3].activation_epoch = spec.far_future_epoch;
+
+        let mut new = old.clone();
+        let epoch = new.current_epoch();
+
+        // Validator 3 becomes active.
+        new.validators[3].activation_epoch = epoch;
+        // Validator 5 exits.
+        new.validators[5].exit_epoch = epoch;
+        // Validator 6 is slashed and exits.
+        new.validators[6].slashed = true;
+        new.validators[6].exit_epoch = epoch;
+
+        // A new validator is added to the registry, but it is not yet active.
+        let mut pending = new.validators[0].clone();
+        pending.activation_epoch = spec.far_future_epoch;
+        new.validators.push(pending).unwrap();
+
+        assert_eq!(
+            registry_diff(&old, &new),
+            RegistryDiff {
+                activated: vec![3],
+                exited: vec![5, 6],
+                slashed: vec![6],
+            }
+        );
+
+        // Deactivations and "un-slashings" are not reported.
+        assert!(registry_diff(&new, &old).is_empty());
+    }
+}
@@ -19,6 +19,18 @@ pub fn keypairs_path() -> PathBuf {
     dir.join(KEYPAIRS_FILE)
 }
 
+/// Describes how the validator keypairs of a `TestingBeaconStateBuilder` were generated.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum KeypairSource {
+    /// Generated by `generate_deterministic_keypairs`, so the same validator count always yields
+    /// the same state.
+    Deterministic,
+    /// A single keypair cloned for every validator.
+    Cloned,
+    /// Supplied by the caller.
+    Provided,
+}
+
 /// Builds a beacon state to be used for testing purposes.
 ///
 /// This struct should **never be used for production purposes.**
@@ -26,6 +38,7 @@ pub fn keypairs_path() -> PathBuf {
 pub struct TestingBeaconStateBuilder<T: EthSpec> {
     state: BeaconState<T>,
     keypairs: Vec<Keypair>,
+    keypair_source: KeypairSource,
 }
 
 impl<T: EthSpec> TestingBeaconStateBuilder<T> {
@@ -33,7 +46,9 @@ impl<T: EthSpec> TestingBeaconStateBuilder<T> {
     pub fn from_deterministic_keypairs(validator_count: usize, spec: &ChainSpec) -> Self {
         debug!("Generating {} deterministic keypairs...", validator_count);
         let keypairs = generate_deterministic_keypairs(validator_count);
-        TestingBeaconStateBuilder::from_keypairs(keypairs, spec)
+        let mut builder = TestingBeaconStateBuilder::from_keypairs(keypairs, spec);
+        builder.keypair_source = KeypairSource::Deterministic;
+        builder
     }
 
     /// Uses the given keypair for all validators.
@@ -49,7 +64,9 @@ impl<T: EthSpec> TestingBeaconStateBuilder<T> {
             keypairs.push(keypair.clone())
         }
 
-        TestingBeaconStateBuilder::from_keypairs(keypairs, spec)
+        let mut builder = TestingBeaconStateBuilder::from_keypairs(keypairs, spec);
+        builder.keypair_source = KeypairSource::Cloned;
+        builder
     }
 
     /// Creates the builder from an existing set of keypairs.
@@ -107,7 +124,16 @@ impl<T: EthSpec> TestingBeaconStateBuilder<T> {
 
         debug!("BeaconState initialized.");
 
-        Self { state, keypairs }
+        Self {
+            state,
+            keypairs,
+            keypair_source: KeypairSource::Provided,
+        }
+    }
+
+    /// Returns how the validator keypairs were generated.
+    pub fn keypair_source(&self) -> KeypairSource {
+        self.keypair_source
     }
 
     /// Consume the builder and return the `BeaconState` and the keypairs for each validator.
@@ -125,7 +151,67 @@ impl<T: EthSpec> TestingBeaconStateBuilder<T> {
         Ok(())
     }
 
+    /// Overrides the balance of each validator, where `balances[i]` is the balance of validator
+    /// `i`. Effective balances are left unchanged.
+    ///
+    /// Returns an error if `balances` does not contain exactly one balance per validator.
+    pub fn set_balances(&mut self, balances: &[u64]) -> Result<&mut Self, String> {
+        if balances.len() != self.state.validators.len() {
+            return Err(format!(
+                "Expected {} balances, got {}",
+                self.state.validators.len(),
+                balances.len()
+            ));
+        }
+
+        self.state.balances = balances.to_vec().into();
+
+        Ok(self)
+    }
+
+    /// Marks each validator in `indices` as slashed, as if it were slashed in the current epoch.
+    ///
+    /// The exit and withdrawable epochs are set as per `slash_validator`, except that the exit
+    /// churn limit is ignored. Balances and the slashings vector are left unchanged.
+    ///
+    /// Returns an error, without modifying any validator, if any index is out of range.
+    pub fn slash_validators(
+        &mut self,
+        indices: &[usize],
+        spec: &ChainSpec,
+    ) -> Result<&mut Self, BeaconStateError> {
+        let state = &mut self.state;
+
+        if let Some(&index) = indices.iter().find(|&&i| i >= state.validators.len()) {
+            return Err(BeaconStateError::UnknownValidator(index as u64));
+        }
+
+        let current_epoch = state.current_epoch();
+        let exit_epoch = state.compute_activation_exit_epoch(current_epoch, spec);
+        let slashings_withdrawable_epoch =
+            current_epoch + Epoch::from(T::EpochsPerSlashingsVector::to_u64());
+
+        for &index in indices {
+            let validator = &mut state.validators[index];
+
+            if validator.exit_epoch == spec.far_future_epoch {
+                validator.exit_epoch = exit_epoch;
+                validator.withdrawable_epoch =
+                    exit_epoch + spec.min_validator_withdrawability_delay;
+            }
+
+            validator.slashed = true;
+            validator.withdrawable_epoch =
+                std::cmp::max(validator.withdrawable_epoch, slashings_withdrawable_epoch);
+        }
+
+        Ok(self)
+    }
+
     /// Sets the `BeaconState` to be in a slot, calling `teleport_to_epoch` to update the epoch.
+    ///
+    /// The slot need not be the first slot of its epoch; the justification/finalization parameters
+    /// are those of the epoch containing `slot`.
     pub fn teleport_to_slot(&mut self, slot: Slot) -> &mut Self {
         self.teleport_to_epoch(slot.epoch(T::slots_per_epoch()));
         self.state.slot = slot;
@@ -136,6 +222,9 @@ impl<T: EthSpec> TestingBeaconStateBuilder<T> {
     ///
     /// Sets all justification/finalization parameters to be be as "perfect" as possible (i.e.,
     /// highest justified and finalized slots, full justification bitfield, etc).
+    ///
+    /// Near genesis the justified and finalized epochs saturate at the genesis epoch, and only the
+    /// justification bits for epochs which exist are set.
     fn teleport_to_epoch(&mut self, epoch: Epoch) {
         let state = &mut self.state;
 
@@ -143,11 +232,18 @@ impl<T: EthSpec> TestingBeaconStateBuilder<T> {
 
         state.slot = slot;
 
-        state.previous_justified_checkpoint.epoch = epoch - 3;
-        state.current_justified_checkpoint.epoch = epoch - 2;
-        state.justification_bits = BitVector::from_bytes(vec![0b0000_1111]).unwrap();
+        state.previous_justified_checkpoint.epoch = epoch.saturating_sub(3u64);
+        state.current_justified_checkpoint.epoch = epoch.saturating_sub(2u64);
+
+        let justification_bits = match epoch.as_u64() {
+            0 => 0b0000_0001,
+            1 => 0b0000_0011,
+            2 => 0b0000_0111,
+            _ => 0b0000_1111,
+        };
+        state.justification_bits = BitVector::from_bytes(vec![justification_bits]).unwrap();
 
-        state.finalized_checkpoint.epoch = epoch - 3;
+        state.finalized_checkpoint.epoch = epoch.saturating_sub(3u64);
     }
 
     /// Creates a full set of attestations for the `BeaconState`. Each attestation has full
@@ -155,6 +251,17 @@ impl<T: EthSpec> TestingBeaconStateBuilder<T> {
     ///
     /// These attestations should be fully conducive to justification and finalization.
     pub fn insert_attestations(&mut self, spec: &ChainSpec) {
+        self.insert_attestations_with_participation(1.0, spec)
+    }
+
+    /// Creates a full set of attestations for the `BeaconState`, like `Self::insert_attestations`,
+    /// except that only the first `fraction` of each committee participates.
+    ///
+    /// The number of participants in each committee is rounded down. A `fraction` of `0.0`
+    /// produces attestations with no aggregation bits set, whilst `1.0` is identical to
+    /// `Self::insert_attestations`. Values outside of this range are clamped to it.
+    pub fn insert_attestations_with_participation(&mut self, fraction: f64, spec: &ChainSpec) {
+        let fraction = fraction.max(0.0).min(1.0);
         let state = &mut self.state;
 
         state
@@ -190,8 +297,12 @@ impl<T: EthSpec> TestingBeaconStateBuilder<T> {
                     slot,
                     spec,
                 );
-                // The entire committee should have signed the pending attestation.
-                let signers = vec![true; beacon_committee.committee.len()];
+                // The first `fraction` of the committee should have signed the pending attestation.
+                let committee_len = beacon_committee.committee.len();
+                let participants = (committee_len as f64 * fraction) as usize;
+                let signers = (0..committee_len)
+                    .map(|position| position < participants)
+                    .collect();
                 builder.add_committee_participation(signers);
                 let attestation = builder.build();
 
@@ -204,3 +315,152 @@ impl<T: EthSpec> TestingBeaconStateBuilder<T> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ssz::Encode;
+
+    type E = MinimalEthSpec;
+
+    #[test]
+    fn set_balances() {
+        let spec = E::default_spec();
+        let mut builder = TestingBeaconStateBuilder::<E>::from_deterministic_keypairs(8, &spec);
+
+        let balances = [spec.max_effective_balance, spec.ejection_balance]
+            .iter()
+            .copied()
+            .cycle()
+            .take(8)
+            .collect::<Vec<_>>();
+
+        builder
+            .set_balances(&balances)
+            .expect("should set balances");
+        assert!(builder.set_balances(&balances[..7]).is_err());
+
+        let (state, _keypairs) = builder.build();
+        assert_eq!(&state.balances[..], &balances[..]);
+    }
+
+    #[test]
+    fn slash_validators() {
+        let spec = E::default_spec();
+        let mut builder = TestingBeaconStateBuilder::<E>::from_deterministic_keypairs(8, &spec);
+
+        builder
+            .slash_validators(&[2, 5], &spec)
+            .expect("should slash validators");
+        assert_eq!(
+            builder.slash_validators(&[1, 8], &spec).err(),
+            Some(BeaconStateError::UnknownValidator(8))
+        );
+
+        let (state, _keypairs) = builder.build();
+        let exit_epoch = state.compute_activation_exit_epoch(state.current_epoch(), &spec);
+
+        for (i, validator) in state.validators.iter().enumerate() {
+            if i == 2 || i == 5 {
+                assert!(validator.slashed);
+                assert_eq!(validator.exit_epoch, exit_epoch);
+                assert_eq!(
+                    validator.withdrawable_epoch,
+                    exit_epoch + spec.min_validator_withdrawability_delay
+                );
+            } else {
+                assert!(!validator.slashed);
+                assert_eq!(validator.exit_epoch, spec.far_future_epoch);
+            }
+        }
+    }
+
+    #[test]
+    fn insert_attestations_with_participation() {
+        let spec = E::default_spec();
+        let mut builder = TestingBeaconStateBuilder::<E>::from_deterministic_keypairs(64, &spec);
+        builder.teleport_to_slot((E::genesis_epoch() + 4).end_slot(E::slots_per_epoch()));
+
+        // With 64 validators, 8 slots per epoch and 2 committees per slot, each committee has 4
+        // members.
+        for &(fraction, expected_bits) in &[(0.0, 0), (0.5, 2), (0.6, 2), (0.75, 3), (1.0, 4)] {
+            let mut builder = builder.clone();
+            builder.insert_attestations_with_participation(fraction, &spec);
+            let (state, _keypairs) = builder.build();
+
+            let attestations = state
+                .previous_epoch_attestations
+                .iter()
+                .chain(state.current_epoch_attestations.iter())
+                .collect::<Vec<_>>();
+
+            assert!(!attestations.is_empty());
+            for attestation in attestations {
+                assert_eq!(attestation.aggregation_bits.len(), 4);
+                assert_eq!(
+                    attestation.aggregation_bits.num_set_bits(),
+                    expected_bits,
+                    "fraction: {}",
+                    fraction
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn deterministic_keypairs_build_identical_states() {
+        let spec = E::default_spec();
+
+        let build = || {
+            let builder = TestingBeaconStateBuilder::<E>::from_deterministic_keypairs(16, &spec);
+            assert_eq!(builder.keypair_source(), KeypairSource::Deterministic);
+            let (state, _keypairs) = builder.build();
+            state
+        };
+
+        assert_eq!(build().as_ssz_bytes(), build().as_ssz_bytes());
+    }
+
+    #[test]
+    fn teleport_to_mid_epoch_slot() {
+        let spec = E::default_spec();
+        let mut builder = TestingBeaconStateBuilder::<E>::from_deterministic_keypairs(8, &spec);
+
+        let slot = Epoch::new(5).start_slot(E::slots_per_epoch()) + 3;
+        builder.teleport_to_slot(slot);
+        let (state, _keypairs) = builder.build();
+
+        assert_eq!(state.slot, slot);
+        assert_eq!(state.current_epoch(), Epoch::new(5));
+        assert_eq!(state.current_justified_checkpoint.epoch, Epoch::new(3));
+        assert_eq!(state.previous_justified_checkpoint.epoch, Epoch::new(2));
+        assert_eq!(state.finalized_checkpoint.epoch, Epoch::new(2));
+        assert_eq!(state.justification_bits.as_ssz_bytes(), vec![0b0000_1111]);
+    }
+
+    #[test]
+    fn teleport_to_slot_near_genesis() {
+        let spec = E::default_spec();
+
+        for (epoch, current_justified, bits) in vec![
+            (0, 0, 0b0000_0001),
+            (1, 0, 0b0000_0011),
+            (2, 0, 0b0000_0111),
+        ] {
+            let mut builder = TestingBeaconStateBuilder::<E>::from_deterministic_keypairs(8, &spec);
+
+            let slot = Epoch::new(epoch).start_slot(E::slots_per_epoch()) + 1;
+            builder.teleport_to_slot(slot);
+            let (state, _keypairs) = builder.build();
+
+            assert_eq!(state.slot, slot);
+            assert_eq!(
+                state.current_justified_checkpoint.epoch,
+                Epoch::new(current_justified)
+            );
+            assert_eq!(state.previous_justified_checkpoint.epoch, Epoch::new(0));
+            assert_eq!(state.finalized_checkpoint.epoch, Epoch::new(0));
+            assert_eq!(state.justification_bits.as_ssz_bytes(), vec![bits]);
+        }
+    }
+}
@@ -110,6 +110,16 @@ mod tests {
         assert_eq!(v.is_active_at(epoch - 1), false);
         assert_eq!(v.is_active_at(epoch), true);
         assert_eq!(v.is_active_at(epoch + 1), true);
+
+        let v = Validator {
+            activation_epoch: epoch,
+            exit_epoch: epoch + 2,
+            ..Validator::default()
+        };
+
+        assert_eq!(v.is_active_at(epoch + 1), true);
+        assert_eq!(v.is_active_at(epoch + 2), false);
+        assert_eq!(v.is_active_at(epoch + 3), false);
     }
 
     #[test]
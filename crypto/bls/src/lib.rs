@@ -82,3 +82,45 @@ pub fn bls_verify_aggregate(
 ) -> bool {
     signature.verify(message, pubkey)
 }
+
+/// Aggregates `keys` into a single public key, against which an aggregate of signatures by each
+/// of `keys` can be verified.
+///
+/// Returns the empty aggregate (the point at infinity) if `keys` is empty.
+pub fn aggregate_public_keys(keys: &[&PublicKey]) -> AggregatePublicKey {
+    let mut aggregate = AggregatePublicKey::new();
+    for key in keys {
+        aggregate.add(key);
+    }
+    aggregate
+}
+
+#[cfg(all(test, not(feature = "fake_crypto")))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn verify_against_aggregate_public_key() {
+        let message = &[42, 42];
+        let keypairs = vec![Keypair::random(), Keypair::random()];
+
+        let mut signature = AggregateSignature::new();
+        for keypair in &keypairs {
+            signature.add(&Signature::new(message, &keypair.sk));
+        }
+
+        let aggregate = aggregate_public_keys(&[&keypairs[0].pk, &keypairs[1].pk]);
+        assert!(bls_verify_aggregate(&aggregate, message, &signature));
+
+        let partial = aggregate_public_keys(&[&keypairs[0].pk]);
+        assert!(!bls_verify_aggregate(&partial, message, &signature));
+    }
+
+    #[test]
+    fn aggregate_no_public_keys() {
+        assert_eq!(
+            aggregate_public_keys(&[]).as_bytes()[..],
+            AggregatePublicKey::new().as_bytes()[..]
+        );
+    }
+}
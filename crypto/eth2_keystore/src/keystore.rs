@@ -22,7 +22,9 @@ use scrypt::{
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 use ssz::DecodeError;
+use std::fs::{File, OpenOptions};
 use std::io::{Read, Write};
+use std::path::Path;
 
 /// The byte-length of a BLS secret key.
 const SECRET_KEY_LEN: usize = 32;
@@ -251,6 +253,43 @@ impl Keystore {
     }
 }
 
+/// Encrypts `keypair` with `password` and writes it to a new file at `path`, as a JSON keystore.
+///
+/// The public key is stored in the clear, the secret key is encrypted with a key derived from
+/// `password` using `crate::default_kdf`.
+///
+/// ## Errors
+///
+/// - If `password == ""`.
+/// - If a file already exists at `path`, or it cannot be written.
+pub fn save_keypair_to_file<P: AsRef<Path>>(
+    keypair: &Keypair,
+    path: P,
+    password: &[u8],
+) -> Result<(), Error> {
+    let keystore = KeystoreBuilder::new(keypair, password, "".into())?.build()?;
+
+    let file = OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(path.as_ref())
+        .map_err(|e| Error::WriteError(format!("{}", e)))?;
+
+    keystore.to_json_writer(file)
+}
+
+/// Reads the JSON keystore at `path` and decrypts its keypair with `password`.
+///
+/// ## Errors
+///
+/// - If `path` cannot be read or does not contain a valid keystore.
+/// - If `password` is incorrect, in which case `Error::InvalidPassword` is returned.
+pub fn load_keypair_from_file<P: AsRef<Path>>(path: P, password: &[u8]) -> Result<Keypair, Error> {
+    let file = File::open(path.as_ref()).map_err(|e| Error::ReadError(format!("{}", e)))?;
+
+    Keystore::from_json_reader(file)?.decrypt_keypair(password)
+}
+
 /// Instantiates a BLS keypair from the given `secret`.
 ///
 /// ## Errors
@@ -9,7 +9,7 @@ pub mod json_keystore;
 pub use bls::SecretHash;
 pub use eth2_key_derivation::PlainText;
 pub use keystore::{
-    decrypt, default_kdf, encrypt, keypair_from_secret, Error, Keystore, KeystoreBuilder, DKLEN,
-    HASH_SIZE, IV_SIZE, SALT_SIZE,
+    decrypt, default_kdf, encrypt, keypair_from_secret, load_keypair_from_file,
+    save_keypair_to_file, Error, Keystore, KeystoreBuilder, DKLEN, HASH_SIZE, IV_SIZE, SALT_SIZE,
 };
 pub use uuid::Uuid;
@@ -5,7 +5,7 @@ use bls::Keypair;
 use eth2_keystore::{
     default_kdf,
     json_keystore::{Kdf, Pbkdf2, Prf, Scrypt},
-    Error, Keystore, KeystoreBuilder, DKLEN,
+    load_keypair_from_file, save_keypair_to_file, Error, Keystore, KeystoreBuilder, DKLEN,
 };
 use std::fs::OpenOptions;
 use tempfile::tempdir;
@@ -87,6 +87,34 @@ fn file() {
     );
 }
 
+#[test]
+fn keypair_file_round_trip() {
+    let keypair = Keypair::random();
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("keypair.json");
+
+    save_keypair_to_file(&keypair, &path, GOOD_PASSWORD).unwrap();
+
+    assert_eq!(
+        load_keypair_from_file(&path, BAD_PASSWORD).err().unwrap(),
+        Error::InvalidPassword,
+        "should not decrypt with bad password"
+    );
+
+    let loaded = load_keypair_from_file(&path, GOOD_PASSWORD).unwrap();
+    assert_eq!(loaded.pk, keypair.pk, "should decrypt with good password");
+    assert_eq!(
+        loaded.sk.as_bytes().as_ref(),
+        keypair.sk.as_bytes().as_ref(),
+        "should decrypt the secret key"
+    );
+
+    assert!(
+        save_keypair_to_file(&keypair, &path, GOOD_PASSWORD).is_err(),
+        "should not overwrite an existing file"
+    );
+}
+
 #[test]
 fn scrypt_params() {
     let keypair = Keypair::random();
@@ -13,6 +13,7 @@ mod parse_hex;
 mod refund_deposit_contract;
 mod skip_slots;
 mod transition_blocks;
+mod yaml_genesis_state;
 
 use clap::{App, Arg, ArgMatches, SubCommand};
 use environment::EnvironmentBuilder;
@@ -284,6 +285,22 @@ fn main() {
                         .help("The value for state.genesis_time."),
                 )
         )
+        .subcommand(
+            SubCommand::with_name("yaml-genesis-state")
+                .about(
+                    "Loads a YAML-encoded BeaconState and writes it as the genesis state of the \
+                    testnet directory. The state is checked against the --spec flag and the \
+                    testnet config first, and nothing is written if it is incompatible.",
+                )
+                .arg(
+                    Arg::with_name("yaml-state")
+                        .index(1)
+                        .value_name("PATH")
+                        .takes_value(true)
+                        .required(true)
+                        .help("The path to the YAML file"),
+                )
+        )
         .subcommand(
             SubCommand::with_name("new-testnet")
                 .about(
@@ -379,6 +396,17 @@ fn main() {
                         .help("The block the deposit contract was deployed. Setting this is a huge
                               optimization for nodes, please do it."),
                 )
+                .arg(
+                    Arg::with_name("spec-override")
+                        .long("spec-override")
+                        .value_name("KEY=VALUE")
+                        .takes_value(true)
+                        .multiple(true)
+                        .number_of_values(1)
+                        .help("Overrides a constant in the testnet config.yaml, e.g.
+                              SECONDS_PER_SLOT=6. Keys are case-insensitive. May be supplied
+                              multiple times. Applied after all other flags."),
+                )
         )
         .subcommand(
             SubCommand::with_name("check-deposit-data")
@@ -566,6 +594,8 @@ fn run<T: EthSpec>(
             .map_err(|e| format!("Failed to run interop-genesis command: {}", e)),
         ("change-genesis-time", Some(matches)) => change_genesis_time::run::<T>(matches)
             .map_err(|e| format!("Failed to run change-genesis-time command: {}", e)),
+        ("yaml-genesis-state", Some(matches)) => yaml_genesis_state::run::<T>(matches)
+            .map_err(|e| format!("Failed to run yaml-genesis-state command: {}", e)),
         ("new-testnet", Some(matches)) => new_testnet::run::<T>(matches)
             .map_err(|e| format!("Failed to run new_testnet command: {}", e)),
         ("check-deposit-data", Some(matches)) => check_deposit_data::run::<T>(matches)
@@ -3,6 +3,7 @@ use clap_utils::{
     parse_optional, parse_path_with_default_in_home_dir, parse_required, parse_ssz_optional,
 };
 use eth2_testnet_config::Eth2TestnetConfig;
+use serde_yaml::Value;
 use std::path::PathBuf;
 use types::{Address, EthSpec, YamlConfig};
 
@@ -53,13 +54,98 @@ pub fn run<T: EthSpec>(matches: &ArgMatches) -> Result<(), String> {
         spec.genesis_fork_version = v;
     }
 
+    let spec_overrides = matches
+        .values_of("spec-override")
+        .map(|values| values.collect::<Vec<_>>())
+        .unwrap_or_default();
+    let yaml_config = apply_spec_overrides(YamlConfig::from_spec::<T>(&spec), &spec_overrides)?;
+
     let testnet: Eth2TestnetConfig<T> = Eth2TestnetConfig {
         deposit_contract_address: format!("{:?}", deposit_contract_address),
         deposit_contract_deploy_block,
         boot_enr: Some(vec![]),
         genesis_state: None,
-        yaml_config: Some(YamlConfig::from_spec::<T>(&spec)),
+        yaml_config: Some(yaml_config),
     };
 
     testnet.write_to_file(testnet_dir_path, overwrite_files)
 }
+
+/// Applies each `KEY=VALUE` override to `config`, where `KEY` is a (case-insensitive) key of the
+/// `config.yaml` file.
+///
+/// Returns an error if a key does not exist or a value is not valid for its key.
+fn apply_spec_overrides(config: YamlConfig, overrides: &[&str]) -> Result<YamlConfig, String> {
+    let mut fields = match serde_yaml::to_value(&config) {
+        Ok(Value::Mapping(fields)) => fields,
+        other => return Err(format!("Unable to encode config: {:?}", other)),
+    };
+
+    for spec_override in overrides {
+        let mut parts = spec_override.splitn(2, '=');
+        let (key, value) = match (parts.next(), parts.next()) {
+            (Some(key), Some(value)) => (key.trim().to_uppercase(), value.trim()),
+            _ => {
+                return Err(format!(
+                    "Invalid spec override {:?}, expected KEY=VALUE",
+                    spec_override
+                ))
+            }
+        };
+
+        let field = fields
+            .get_mut(&Value::String(key.clone()))
+            .ok_or_else(|| format!("Unknown spec constant: {}", key))?;
+
+        // Hex values such as the fork version are encoded as strings, which YAML would otherwise
+        // parse as integers.
+        *field = match field {
+            Value::String(_) => Value::String(value.to_string()),
+            _ => serde_yaml::from_str(value)
+                .map_err(|e| format!("Invalid value for {}: {:?}", key, e))?,
+        };
+
+        serde_yaml::from_value::<YamlConfig>(Value::Mapping(fields.clone()))
+            .map_err(|e| format!("Invalid value for {}: {:?}", key, e))?;
+    }
+
+    serde_yaml::from_value(Value::Mapping(fields))
+        .map_err(|e| format!("Unable to decode config: {:?}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use types::{ChainSpec, MainnetEthSpec};
+
+    type E = MainnetEthSpec;
+
+    #[test]
+    fn spec_override() {
+        let spec = E::default_spec();
+        let config = YamlConfig::from_spec::<E>(&spec);
+
+        let overridden = apply_spec_overrides(config, &["seconds_per_slot=6"])
+            .unwrap()
+            .apply_to_chain_spec::<E>(&spec)
+            .unwrap();
+
+        assert_eq!(overridden.milliseconds_per_slot, 6_000);
+        assert_eq!(
+            ChainSpec {
+                milliseconds_per_slot: spec.milliseconds_per_slot,
+                ..overridden
+            },
+            spec
+        );
+    }
+
+    #[test]
+    fn invalid_spec_override() {
+        let config = || YamlConfig::from_spec::<E>(&E::default_spec());
+
+        assert!(apply_spec_overrides(config(), &["NOT_A_CONSTANT=1"]).is_err());
+        assert!(apply_spec_overrides(config(), &["SECONDS_PER_SLOT=six"]).is_err());
+        assert!(apply_spec_overrides(config(), &["SECONDS_PER_SLOT"]).is_err());
+    }
+}
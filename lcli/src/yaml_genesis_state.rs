@@ -0,0 +1,192 @@
+use clap::ArgMatches;
+use clap_utils::{parse_path_with_default_in_home_dir, parse_required};
+use eth2_testnet_config::Eth2TestnetConfig;
+use state_processing::is_valid_genesis_state;
+use std::fs;
+use std::path::PathBuf;
+use types::{diff_specs, BeaconState, ChainSpec, EthSpec, MainnetEthSpec, MinimalEthSpec};
+
+pub fn run<T: EthSpec>(matches: &ArgMatches) -> Result<(), String> {
+    let yaml_path: PathBuf = parse_required(matches, "yaml-state")?;
+    let testnet_dir = parse_path_with_default_in_home_dir(
+        matches,
+        "testnet-dir",
+        PathBuf::from(".lighthouse/testnet"),
+    )?;
+
+    let yaml =
+        fs::read(&yaml_path).map_err(|e| format!("Unable to read {:?}: {:?}", yaml_path, e))?;
+
+    import_yaml_genesis_state::<T>(&yaml, testnet_dir)
+}
+
+/// Checks that `yaml` is a valid genesis state for the testnet in `testnet_dir`, then writes it to
+/// the testnet's `genesis.ssz`.
+///
+/// Nothing is written if the check fails.
+fn import_yaml_genesis_state<T: EthSpec>(yaml: &[u8], testnet_dir: PathBuf) -> Result<(), String> {
+    let mut eth2_testnet_config: Eth2TestnetConfig<T> =
+        Eth2TestnetConfig::load(testnet_dir.clone())?;
+
+    let spec = eth2_testnet_config
+        .yaml_config
+        .as_ref()
+        .ok_or_else(|| "The testnet directory must contain a spec config".to_string())?
+        .apply_to_chain_spec::<T>(&T::default_spec())
+        .ok_or_else(|| {
+            format!(
+                "The testnet config is not compatible with the {} spec",
+                T::spec_name()
+            )
+        })?;
+
+    let genesis_state = check_genesis_state::<T>(yaml, &spec)?;
+
+    eth2_testnet_config.genesis_state = Some(genesis_state);
+    eth2_testnet_config.force_write_to_file(testnet_dir)
+}
+
+/// Decodes `yaml` as a `BeaconState<T>` and checks that it is a valid genesis state for `spec`.
+///
+/// The returned error explains why the state is incompatible.
+fn check_genesis_state<T: EthSpec>(
+    yaml: &[u8],
+    spec: &ChainSpec,
+) -> Result<BeaconState<T>, String> {
+    let state: BeaconState<T> =
+        serde_yaml::from_slice(yaml).map_err(|e| format!("Unable to parse YAML state: {:?}", e))?;
+
+    check_vector_lengths(&state)?;
+
+    if state.fork.current_version != spec.genesis_fork_version {
+        return Err(format!(
+            "The state has fork version {:?}, but the testnet genesis fork version is {:?}",
+            state.fork.current_version, spec.genesis_fork_version
+        ));
+    }
+
+    if !is_valid_genesis_state(&state, spec) {
+        let active_validators = state
+            .validators
+            .iter()
+            .filter(|validator| validator.is_active_at(T::genesis_epoch()))
+            .count();
+
+        return Err(format!(
+            "The state is not a valid genesis state. It has genesis time {} (minimum {}) and {} \
+             active validators (minimum {})",
+            state.genesis_time,
+            spec.min_genesis_time,
+            active_validators,
+            spec.min_genesis_active_validator_count
+        ));
+    }
+
+    Ok(state)
+}
+
+/// Returns an error if the fixed-length vectors of `state` do not have the lengths `T` requires.
+///
+/// Vector lengths are not checked when decoding YAML, so a state built for a different spec
+/// decodes successfully and only fails once it is used.
+fn check_vector_lengths<T: EthSpec>(state: &BeaconState<T>) -> Result<(), String> {
+    let lengths = (state.block_roots.len(), state.randao_mixes.len());
+    if lengths == vector_lengths::<T>() {
+        return Ok(());
+    }
+
+    let (other_name, other_spec) = if lengths == vector_lengths::<MinimalEthSpec>() {
+        (MinimalEthSpec::spec_name(), MinimalEthSpec::default_spec())
+    } else if lengths == vector_lengths::<MainnetEthSpec>() {
+        (MainnetEthSpec::spec_name(), MainnetEthSpec::default_spec())
+    } else {
+        let (block_roots, randao_mixes) = vector_lengths::<T>();
+        return Err(format!(
+            "The state has {} block roots and {} randao mixes, but the {} spec requires {} and {}",
+            lengths.0,
+            lengths.1,
+            T::spec_name(),
+            block_roots,
+            randao_mixes
+        ));
+    };
+
+    let differences = diff_specs(&T::default_spec(), &other_spec)?
+        .into_iter()
+        .map(|(field, ours, theirs)| format!("{} ({} vs {})", field, ours, theirs))
+        .collect::<Vec<_>>();
+
+    Err(format!(
+        "The state was built for the {} spec, but --spec is {}. Differing constants: {}",
+        other_name,
+        T::spec_name(),
+        differences.join(", ")
+    ))
+}
+
+/// Returns the required lengths of the `block_roots` and `randao_mixes` vectors for `E`.
+fn vector_lengths<E: EthSpec>() -> (usize, usize) {
+    (
+        E::slots_per_historical_root(),
+        E::epochs_per_historical_vector(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+    use tempfile::tempdir;
+    use types::{Eth1Data, YamlConfig};
+
+    /// Writes a testnet directory without a genesis state.
+    fn testnet_dir<T: EthSpec>(path: &Path) {
+        Eth2TestnetConfig::<T> {
+            deposit_contract_address: "0x0000000000000000000000000000000000000000".to_string(),
+            deposit_contract_deploy_block: 0,
+            boot_enr: Some(vec![]),
+            genesis_state: None,
+            yaml_config: Some(YamlConfig::from_spec::<T>(&T::default_spec())),
+        }
+        .write_to_file(path.to_path_buf(), false)
+        .unwrap();
+    }
+
+    #[test]
+    fn rejects_state_for_other_spec() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("testnet");
+        testnet_dir::<MinimalEthSpec>(&path);
+
+        let spec = MainnetEthSpec::default_spec();
+        let state = BeaconState::<MainnetEthSpec>::new(0, Eth1Data::default(), &spec);
+        let yaml = serde_yaml::to_vec(&state).unwrap();
+
+        let error = import_yaml_genesis_state::<MinimalEthSpec>(&yaml, path.clone()).unwrap_err();
+        assert!(
+            error.starts_with("The state was built for the mainnet spec, but --spec is minimal"),
+            "unexpected error: {}",
+            error
+        );
+        assert!(!path.join("genesis.ssz").exists());
+    }
+
+    #[test]
+    fn rejects_invalid_genesis_state() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("testnet");
+        testnet_dir::<MinimalEthSpec>(&path);
+
+        let spec = MinimalEthSpec::default_spec();
+        let state = BeaconState::<MinimalEthSpec>::new(0, Eth1Data::default(), &spec);
+        let yaml = serde_yaml::to_vec(&state).unwrap();
+
+        let error = import_yaml_genesis_state::<MinimalEthSpec>(&yaml, path.clone()).unwrap_err();
+        assert!(
+            error.contains("0 active validators (minimum 64)"),
+            "unexpected error: {}",
+            error
+        );
+        assert!(!path.join("genesis.ssz").exists());
+    }
+}
@@ -199,6 +199,13 @@ fn run<E: EthSpec>(
         return Ok(());
     };
 
+    if let Some(bn_matches) = matches.subcommand_matches("beacon_node") {
+        if let Some(db_matches) = bn_matches.subcommand_matches(beacon_node::db::CMD) {
+            // Exit as soon as the database command returns control.
+            return beacon_node::db::cli_run(bn_matches, db_matches, environment.core_context());
+        }
+    }
+
     warn!(
         log,
         "Ethereum 2.0 is pre-release. This software is experimental."
@@ -1,6 +1,6 @@
 use crate::local_network::LocalNetwork;
 use std::time::Duration;
-use types::{Epoch, EthSpec};
+use types::{Epoch, EthSpec, Slot};
 
 /// Checks that the chain has made the first possible finalization.
 ///
@@ -51,3 +51,252 @@ pub async fn verify_all_finalized_at<E: EthSpec>(
         Ok(())
     }
 }
+
+/// Verifies that, on every beacon node in the given network, the validators that attested in the
+/// epoch prior to the previous epoch hold at least `min_fraction` of the active balance.
+///
+/// That epoch is used since its attestations are no longer eligible for inclusion, so its
+/// participation is final.
+pub async fn verify_attestation_participation<E: EthSpec>(
+    network: LocalNetwork<E>,
+    min_fraction: f64,
+) -> Result<(), String> {
+    let participation = {
+        let mut participation = Vec::new();
+        for remote_node in network.remote_nodes()? {
+            let head_epoch = remote_node
+                .http
+                .beacon()
+                .get_head()
+                .await
+                .map(|head| head.slot.epoch(E::slots_per_epoch()))
+                .map_err(|e| format!("Get head via http failed: {:?}", e))?;
+
+            if head_epoch < 2 {
+                return Err(format!(
+                    "Cannot check participation before epoch 2. Head epoch: {}",
+                    head_epoch
+                ));
+            }
+
+            // The "previous epoch" of this vote count is `head_epoch - 2`.
+            let vote_count = remote_node
+                .http
+                .consensus()
+                .get_vote_count(head_epoch - 1)
+                .await
+                .map_err(|e| format!("Get vote count via http failed: {:?}", e))?;
+
+            participation.push((
+                vote_count.previous_epoch_attesting_gwei,
+                vote_count.previous_epoch_active_gwei,
+            ));
+        }
+        participation
+    };
+
+    check_participation(&participation, min_fraction)
+}
+
+/// Returns an error naming each node whose `(attesting_gwei, active_gwei)` in `participation`
+/// gives a participation rate below `min_fraction`.
+///
+/// Nodes are named by their index in `participation`.
+fn check_participation(participation: &[(u64, u64)], min_fraction: f64) -> Result<(), String> {
+    let lagging = participation
+        .iter()
+        .enumerate()
+        .filter_map(|(i, (attesting_gwei, active_gwei))| {
+            let fraction = if *active_gwei == 0 {
+                0.0
+            } else {
+                *attesting_gwei as f64 / *active_gwei as f64
+            };
+
+            if fraction < min_fraction {
+                Some(format!("node {} ({:.3})", i, fraction))
+            } else {
+                None
+            }
+        })
+        .collect::<Vec<_>>();
+
+    if lagging.is_empty() {
+        Ok(())
+    } else {
+        Err(format!(
+            "Attestation participation is below {}: {}",
+            min_fraction,
+            lagging.join(", ")
+        ))
+    }
+}
+
+/// Verifies that no proposer or attester slashings have been included in the canonical chain of
+/// any beacon node in the given network.
+pub async fn verify_no_slashings<E: EthSpec>(network: LocalNetwork<E>) -> Result<(), String> {
+    let counts = {
+        let mut counts = Vec::new();
+        for remote_node in network.remote_nodes()? {
+            let beacon = remote_node.http.beacon();
+            let mut block_root = beacon
+                .get_head()
+                .await
+                .map(|head| head.block_root)
+                .map_err(|e| format!("Get head via http failed: {:?}", e))?;
+
+            let mut count = SlashingCount::default();
+            loop {
+                let (block, _) = beacon
+                    .get_block_by_root(block_root)
+                    .await
+                    .map_err(|e| format!("Get block via http failed: {:?}", e))?;
+
+                if block.slot() == Slot::new(0) {
+                    break;
+                }
+
+                let body = &block.message.body;
+                count.proposer_slashings += body.proposer_slashings.len();
+                count.attester_slashings += body.attester_slashings.len();
+                block_root = block.parent_root();
+            }
+            counts.push(count);
+        }
+        counts
+    };
+
+    check_no_slashings(&counts)
+}
+
+/// The number of each type of slashing included in a chain.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+struct SlashingCount {
+    proposer_slashings: usize,
+    attester_slashings: usize,
+}
+
+/// Returns an error naming each node in `counts` with a non-zero count of either slashing type.
+///
+/// Nodes are named by their index in `counts`.
+fn check_no_slashings(counts: &[SlashingCount]) -> Result<(), String> {
+    let mut failures = vec![];
+    for (i, count) in counts.iter().enumerate() {
+        if count.proposer_slashings > 0 {
+            failures.push(format!(
+                "node {} has {} proposer slashings",
+                i, count.proposer_slashings
+            ));
+        }
+        if count.attester_slashings > 0 {
+            failures.push(format!(
+                "node {} has {} attester slashings",
+                i, count.attester_slashings
+            ));
+        }
+    }
+
+    if failures.is_empty() {
+        Ok(())
+    } else {
+        Err(format!(
+            "Slashings were included on chain: {}",
+            failures.join(", ")
+        ))
+    }
+}
+
+/// Verifies that every beacon node in the given network is connected to at least `min_peers`
+/// peers.
+pub async fn verify_peer_count<E: EthSpec>(
+    network: LocalNetwork<E>,
+    min_peers: usize,
+) -> Result<(), String> {
+    let peer_counts = {
+        let mut peer_counts = Vec::new();
+        for remote_node in network.remote_nodes()? {
+            peer_counts.push(
+                remote_node
+                    .http
+                    .network()
+                    .get_peer_count()
+                    .await
+                    .map_err(|e| format!("Get peer count via http failed: {:?}", e))?,
+            );
+        }
+        peer_counts
+    };
+
+    check_peer_counts(&peer_counts, min_peers)
+}
+
+/// Returns an error naming each node in `peer_counts` with fewer than `min_peers` peers.
+///
+/// Nodes are named by their index in `peer_counts`.
+fn check_peer_counts(peer_counts: &[usize], min_peers: usize) -> Result<(), String> {
+    let under_peered = peer_counts
+        .iter()
+        .enumerate()
+        .filter(|(_, peer_count)| **peer_count < min_peers)
+        .map(|(i, peer_count)| format!("node {} has {} peers", i, peer_count))
+        .collect::<Vec<_>>();
+
+    if under_peered.is_empty() {
+        Ok(())
+    } else {
+        Err(format!(
+            "Nodes have fewer than {} peers: {}",
+            min_peers,
+            under_peered.join(", ")
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn participation_threshold() {
+        assert!(check_participation(&[(32, 32), (24, 32)], 0.75).is_ok());
+        assert!(check_participation(&[], 0.75).is_ok());
+
+        assert_eq!(
+            check_participation(&[(32, 32), (23, 32), (0, 0)], 0.75),
+            Err("Attestation participation is below 0.75: node 1 (0.719), node 2 (0.000)".into())
+        );
+    }
+
+    #[test]
+    fn slashing_counts() {
+        let clean = SlashingCount::default();
+        assert!(check_no_slashings(&[clean, clean]).is_ok());
+
+        let proposer = SlashingCount {
+            proposer_slashings: 1,
+            attester_slashings: 0,
+        };
+        let both = SlashingCount {
+            proposer_slashings: 2,
+            attester_slashings: 3,
+        };
+        assert_eq!(
+            check_no_slashings(&[clean, proposer, both]),
+            Err(
+                "Slashings were included on chain: node 1 has 1 proposer slashings, \
+                 node 2 has 2 proposer slashings, node 2 has 3 attester slashings"
+                    .into()
+            )
+        );
+    }
+
+    #[test]
+    fn peer_counts() {
+        assert!(check_peer_counts(&[3, 2, 3], 2).is_ok());
+
+        assert_eq!(
+            check_peer_counts(&[3, 1, 2, 0], 2),
+            Err("Nodes have fewer than 2 peers: node 1 has 1 peers, node 3 has 0 peers".into())
+        );
+    }
+}
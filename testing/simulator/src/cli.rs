@@ -32,6 +32,18 @@ pub fn cli_app<'a, 'b>() -> App<'a, 'b> {
                         .long("end_after_checks")
                         .takes_value(false)
                         .help("End after checks (default true)"))
+                    .arg(Arg::with_name("topology")
+                        .long("topology")
+                        .takes_value(true)
+                        .possible_values(&["full-mesh", "ring", "star"])
+                        .default_value("star")
+                        .help("Which beacon nodes peer with each other. With \"star\", nodes boot \
+                            from the first node and discover each other"))
+                    .arg(Arg::with_name("min_participation")
+                        .long("min_participation")
+                        .takes_value(true)
+                        .help("If present, also checks that at least this fraction of the active \
+                            balance attested in the epoch two prior to each node's head"))
         )
         .subcommand(
             SubCommand::with_name("syncing-sim")
@@ -66,6 +78,56 @@ pub fn cli_app<'a, 'b>() -> App<'a, 'b> {
                         .default_value("all")
                         .possible_values(&["one-node", "two-nodes", "mixed", "all"])
                         .help("Sync verification strategy to run."),
+                )
+                .arg(
+                    Arg::with_name("partition-at-epoch")
+                        .long("partition-at-epoch")
+                        .takes_value(true)
+                        .requires("heal-at-epoch")
+                        .help(
+                            "After the strategy completes, split the nodes into two halves \
+                            that cannot peer with each other from this epoch onwards.",
+                        ),
+                )
+                .arg(
+                    Arg::with_name("heal-at-epoch")
+                        .long("heal-at-epoch")
+                        .takes_value(true)
+                        .requires("partition-at-epoch")
+                        .help(
+                            "The epoch at which the partition ends, after which all nodes must \
+                            re-converge on the same finalized epoch.",
+                        ),
                 ),
         )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::topology::Topology;
+
+    fn topology(args: &[&str]) -> Topology {
+        let matches = cli_app().get_matches_from(args);
+        let matches = matches
+            .subcommand_matches("no-eth1-sim")
+            .expect("should match no-eth1-sim");
+        value_t!(matches, "topology", Topology).expect("should parse topology")
+    }
+
+    #[test]
+    fn parse_topology() {
+        assert_eq!(topology(&["simulator", "no-eth1-sim"]), Topology::Star);
+        assert_eq!(
+            topology(&["simulator", "no-eth1-sim", "--topology", "ring"]),
+            Topology::Ring
+        );
+        assert_eq!(
+            topology(&["simulator", "no-eth1-sim", "--topology", "full-mesh"]),
+            Topology::FullMesh
+        );
+        assert!(cli_app()
+            .get_matches_from_safe(&["simulator", "no-eth1-sim", "--topology", "tree"])
+            .is_err());
+    }
+}
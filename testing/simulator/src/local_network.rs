@@ -1,3 +1,4 @@
+use crate::topology::Topology;
 use node_test_rig::{
     environment::RuntimeContext, ClientConfig, LocalBeaconNode, LocalValidatorClient,
     RemoteBeaconNode, ValidatorConfig, ValidatorFiles,
@@ -5,6 +6,7 @@ use node_test_rig::{
 use parking_lot::RwLock;
 use std::ops::Deref;
 use std::sync::Arc;
+use std::time::Duration;
 use types::{Epoch, EthSpec};
 
 const BOOTNODE_PORT: u16 = 42424;
@@ -12,6 +14,8 @@ const BOOTNODE_PORT: u16 = 42424;
 /// Helper struct to reduce `Arc` usage.
 pub struct Inner<E: EthSpec> {
     context: RuntimeContext<E>,
+    topology: Topology,
+    node_count: usize,
     beacon_nodes: RwLock<Vec<LocalBeaconNode<E>>>,
     validator_clients: RwLock<Vec<LocalValidatorClient<E>>>,
 }
@@ -40,11 +44,26 @@ impl<E: EthSpec> Deref for LocalNetwork<E> {
 }
 
 impl<E: EthSpec> LocalNetwork<E> {
-    /// Creates a new network with a single `BeaconNode`.
+    /// Creates a new network with a single `BeaconNode`, to which subsequent nodes connect via
+    /// discovery.
     pub async fn new(
+        context: RuntimeContext<E>,
+        beacon_config: ClientConfig,
+    ) -> Result<Self, String> {
+        Self::with_topology(context, beacon_config, Topology::default(), 1).await
+    }
+
+    /// Creates a new network with a single `BeaconNode`, which will have `node_count` nodes once
+    /// they have all been added. Nodes are connected according to `topology`.
+    pub async fn with_topology(
         context: RuntimeContext<E>,
         mut beacon_config: ClientConfig,
+        topology: Topology,
+        node_count: usize,
     ) -> Result<Self, String> {
+        if topology != Topology::Star {
+            beacon_config.network.disable_discovery = true;
+        }
         beacon_config.network.discovery_port = BOOTNODE_PORT;
         beacon_config.network.libp2p_port = BOOTNODE_PORT;
         beacon_config.network.enr_udp_port = Some(BOOTNODE_PORT);
@@ -55,6 +74,8 @@ impl<E: EthSpec> LocalNetwork<E> {
         Ok(Self {
             inner: Arc::new(Inner {
                 context,
+                topology,
+                node_count,
                 beacon_nodes: RwLock::new(vec![beacon_node]),
                 validator_clients: RwLock::new(vec![]),
             }),
@@ -77,21 +98,42 @@ impl<E: EthSpec> LocalNetwork<E> {
         self.validator_clients.read().len()
     }
 
-    /// Adds a beacon node to the network, connecting to the 0'th beacon node via ENR.
+    /// Adds a beacon node to the network, connecting it to the existing nodes that are its peers
+    /// in the network's topology.
+    ///
+    /// With `Topology::Star`, the node is given the 0'th beacon node as a boot node. Otherwise,
+    /// discovery is disabled and the node dials each of its peers directly.
     pub async fn add_beacon_node(&self, mut beacon_config: ClientConfig) -> Result<(), String> {
         let self_1 = self.clone();
         println!("Adding beacon node..");
         {
             let read_lock = self.beacon_nodes.read();
 
-            let boot_node = read_lock.first().expect("should have at least one node");
-
-            beacon_config.network.boot_nodes.push(
-                boot_node
-                    .client
-                    .enr()
-                    .expect("bootnode must have a network"),
-            );
+            if self.topology == Topology::Star {
+                let boot_node = read_lock.first().expect("should have at least one node");
+
+                beacon_config.network.boot_nodes.push(
+                    boot_node
+                        .client
+                        .enr()
+                        .expect("bootnode must have a network"),
+                );
+            } else {
+                beacon_config.network.disable_discovery = true;
+
+                let index = read_lock.len();
+                for peer in self.topology.peers(index, self.node_count) {
+                    // Peers that have not yet been added will dial this node themselves.
+                    if let Some(peer_node) = read_lock.get(peer) {
+                        let addr = peer_node
+                            .client
+                            .libp2p_listen_addresses()
+                            .and_then(|addrs| addrs.into_iter().next())
+                            .ok_or_else(|| format!("Node {} has no listen address", peer))?;
+                        beacon_config.network.libp2p_nodes.push(addr);
+                    }
+                }
+            }
         }
 
         let index = self.beacon_nodes.read().len();
@@ -127,8 +169,11 @@ impl<E: EthSpec> LocalNetwork<E> {
                 .expect("Must have http started")
         };
 
-        validator_config.http_server =
-            format!("http://{}:{}", socket_addr.ip(), socket_addr.port());
+        validator_config.http_servers = vec![format!(
+            "http://{}:{}",
+            socket_addr.ip(),
+            socket_addr.port()
+        )];
         let validator_client = LocalValidatorClient::production_with_insecure_keypairs(
             context,
             validator_config,
@@ -139,6 +184,29 @@ impl<E: EthSpec> LocalNetwork<E> {
         Ok(())
     }
 
+    /// Splits the beacon nodes into two halves, disconnecting every node from each node in the
+    /// other half. The halves may not reconnect until `duration` has elapsed, after which peers
+    /// are found again via discovery or by redialing.
+    pub fn partition(&self, duration: Duration) -> Result<(), String> {
+        let beacon_nodes = self.beacon_nodes.read();
+        let (first_half, second_half) = beacon_nodes.split_at(beacon_nodes.len() / 2);
+
+        let peer_id = |node: &LocalBeaconNode<E>| {
+            node.client
+                .peer_id()
+                .ok_or_else(|| "Beacon node must have a network".to_string())
+        };
+
+        for node in first_half {
+            for other in second_half {
+                node.client.ban_peer(peer_id(other)?, duration)?;
+                other.client.ban_peer(peer_id(node)?, duration)?;
+            }
+        }
+
+        Ok(())
+    }
+
     /// For all beacon nodes in `Self`, return a HTTP client to access each nodes HTTP API.
     pub fn remote_nodes(&self) -> Result<Vec<RemoteBeaconNode<E>>, String> {
         let beacon_nodes = self.beacon_nodes.read();
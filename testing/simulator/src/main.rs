@@ -21,6 +21,7 @@ mod cli;
 mod local_network;
 mod no_eth1_sim;
 mod sync_sim;
+mod topology;
 
 use cli::cli_app;
 use env_logger::{Builder, Env};
@@ -1,4 +1,4 @@
-use crate::{checks, LocalNetwork};
+use crate::{checks, topology::Topology, LocalNetwork};
 use clap::ArgMatches;
 use futures::prelude::*;
 use node_test_rig::{
@@ -16,6 +16,13 @@ pub fn run_no_eth1_sim(matches: &ArgMatches) -> Result<(), String> {
         .expect("missing validators_per_node default");
     let speed_up_factor =
         value_t!(matches, "speed_up_factor", u64).expect("missing speed_up_factor default");
+    let topology: Topology =
+        value_t!(matches, "topology", Topology).expect("missing topology default");
+    let min_participation = if matches.is_present("min_participation") {
+        Some(value_t!(matches, "min_participation", f64).map_err(|e| e.to_string())?)
+    } else {
+        None
+    };
     let mut end_after_checks = true;
     if matches.is_present("end_after_checks") {
         end_after_checks = false;
@@ -25,6 +32,8 @@ pub fn run_no_eth1_sim(matches: &ArgMatches) -> Result<(), String> {
     println!(" nodes:{}", node_count);
     println!(" validators_per_node:{}", validators_per_node);
     println!(" end_after_checks:{}", end_after_checks);
+    println!(" topology:{:?}", topology);
+    println!(" min_participation:{:?}", min_participation);
 
     // Generate the directories and keystores required for the validator clients.
     let validator_files = (0..node_count)
@@ -83,7 +92,9 @@ pub fn run_no_eth1_sim(matches: &ArgMatches) -> Result<(), String> {
     beacon_config.network.enr_address = Some(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)));
 
     let main_future = async {
-        let network = LocalNetwork::new(context, beacon_config.clone()).await?;
+        let network =
+            LocalNetwork::with_topology(context, beacon_config.clone(), topology, node_count)
+                .await?;
         /*
          * One by one, add beacon nodes to the network.
          */
@@ -122,6 +133,22 @@ pub fn run_no_eth1_sim(matches: &ArgMatches) -> Result<(), String> {
             // Check that the chain finalizes at the first given opportunity.
             checks::verify_first_finalization(network.clone(), slot_duration).await?;
 
+            // Check that each node is connected to at least as many peers as the topology
+            // configures for any node.
+            let min_peers = (0..node_count)
+                .map(|index| topology.peers(index, node_count).len())
+                .min()
+                .unwrap_or(0);
+            checks::verify_peer_count(network.clone(), min_peers).await?;
+
+            // Check that no validators have been slashed.
+            checks::verify_no_slashings(network.clone()).await?;
+
+            // Optionally check that validators attested at the expected rate.
+            if let Some(min_fraction) = min_participation {
+                checks::verify_attestation_participation(network.clone(), min_fraction).await?;
+            }
+
             Ok::<(), String>(())
         };
 
@@ -16,12 +16,24 @@ pub fn run_syncing_sim(matches: &ArgMatches) -> Result<(), String> {
     let sync_timeout = value_t!(matches, "sync_timeout", u64).unwrap();
     let speed_up_factor = value_t!(matches, "speedup", u64).unwrap();
     let strategy = value_t!(matches, "strategy", String).unwrap();
+    let partition = if matches.is_present("partition-at-epoch") {
+        let partition_at =
+            value_t!(matches, "partition-at-epoch", u64).map_err(|e| e.to_string())?;
+        let heal_at = value_t!(matches, "heal-at-epoch", u64).map_err(|e| e.to_string())?;
+        if heal_at <= partition_at {
+            return Err("--heal-at-epoch must be after --partition-at-epoch".into());
+        }
+        Some((Epoch::new(partition_at), Epoch::new(heal_at)))
+    } else {
+        None
+    };
 
     println!("Syncing Simulator:");
     println!(" initial_delay:{}", initial_delay);
     println!(" sync timeout: {}", sync_timeout);
     println!(" speed up factor:{}", speed_up_factor);
     println!(" strategy:{}", strategy);
+    println!(" partition:{:?}", partition);
 
     let log_level = "debug";
     let log_format = None;
@@ -31,6 +43,7 @@ pub fn run_syncing_sim(matches: &ArgMatches) -> Result<(), String> {
         initial_delay,
         sync_timeout,
         strategy,
+        partition,
         log_level,
         log_format,
     )
@@ -41,6 +54,7 @@ fn syncing_sim(
     initial_delay: u64,
     sync_timeout: u64,
     strategy: String,
+    partition: Option<(Epoch, Epoch)>,
     log_level: &str,
     log_format: Option<&str>,
 ) -> Result<(), String> {
@@ -104,6 +118,20 @@ fn syncing_sim(
         )
         .await?;
 
+        // Optionally partition the network and check that it re-converges once healed.
+        if let Some((partition_at, heal_at)) = partition {
+            verify_partition_recovery(
+                network.clone(),
+                genesis_time,
+                slot_duration,
+                partition_at,
+                heal_at,
+                sync_timeout,
+            )
+            .await?;
+            println!("Completed partition recovery");
+        }
+
         // The `final_future` either completes immediately or never completes, depending on the value
         // of `end_after_checks`.
 
@@ -342,6 +370,51 @@ pub async fn verify_syncing<E: EthSpec>(
     Ok(())
 }
 
+/// Partitions the network from the start of `partition_at` until the start of `heal_at`, then
+/// verifies that all nodes are finalized at the same epoch within `sync_timeout` epochs.
+///
+/// If `partition_at` has already passed, the partition starts immediately and lasts for the
+/// same number of epochs.
+pub async fn verify_partition_recovery<E: EthSpec>(
+    network: LocalNetwork<E>,
+    genesis_time: Duration,
+    slot_duration: Duration,
+    partition_at: Epoch,
+    heal_at: Epoch,
+    sync_timeout: u64,
+) -> Result<(), String> {
+    let epoch_duration = slot_duration * (E::slots_per_epoch() as u32);
+    let partition_duration = epoch_duration * (heal_at - partition_at).as_u64() as u32;
+
+    let partition_start = genesis_time + epoch_duration * partition_at.as_u64() as u32;
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_err(|_| "should get system time")?;
+    if let Some(delay) = partition_start.checked_sub(now) {
+        tokio::time::delay_for(delay).await;
+    }
+
+    println!("Partitioning network");
+    network.partition(partition_duration)?;
+    tokio::time::delay_for(partition_duration).await;
+    println!("Healing network");
+
+    // Check every `epoch_duration` if nodes are synced
+    // limited to at most `sync_timeout` epochs
+    let mut interval = tokio::time::interval(epoch_duration);
+    let mut count = 0;
+    while let Some(_) = interval.next().await {
+        if count >= sync_timeout || !check_still_syncing(&network).await? {
+            break;
+        }
+        count += 1;
+    }
+    let epoch = network.bootnode_epoch().await?;
+    verify_all_finalized_at(network, epoch)
+        .map_err(|e| format!("Partition recovery error: {}", e))
+        .await
+}
+
 pub async fn check_still_syncing<E: EthSpec>(network: &LocalNetwork<E>) -> Result<bool, String> {
     // get syncing status of nodes
     let mut status = Vec::new();
@@ -358,3 +431,24 @@ pub async fn check_still_syncing<E: EthSpec>(network: &LocalNetwork<E>) -> Resul
     }
     Ok(status.iter().any(|is_syncing| *is_syncing))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Runs a full simulation, so it is slow and requires free local ports.
+    #[test]
+    #[ignore]
+    fn finalization_resumes_after_partition() {
+        syncing_sim(
+            15,
+            5,
+            10,
+            "one-node".into(),
+            Some((Epoch::new(16), Epoch::new(20))),
+            "error",
+            None,
+        )
+        .unwrap();
+    }
+}
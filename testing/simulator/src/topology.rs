@@ -0,0 +1,70 @@
+use std::str::FromStr;
+
+/// Determines which beacon nodes in a `LocalNetwork` are configured to peer with each other.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Topology {
+    /// Every node peers with every other node.
+    FullMesh,
+    /// Each node peers with the nodes immediately before and after it, with the last node peering
+    /// with the first.
+    Ring,
+    /// Every node is given the first node as its boot node and relies upon discovery to find
+    /// further peers.
+    Star,
+}
+
+impl Default for Topology {
+    fn default() -> Self {
+        Topology::Star
+    }
+}
+
+impl FromStr for Topology {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, String> {
+        match s {
+            "full-mesh" => Ok(Topology::FullMesh),
+            "ring" => Ok(Topology::Ring),
+            "star" => Ok(Topology::Star),
+            other => Err(format!("Unknown topology: {}", other)),
+        }
+    }
+}
+
+impl Topology {
+    /// Returns the indices of the nodes that the node at `index` is configured to peer with, in a
+    /// network of `node_count` nodes.
+    pub fn peers(&self, index: usize, node_count: usize) -> Vec<usize> {
+        let mut peers = match self {
+            Topology::FullMesh => (0..node_count).collect(),
+            Topology::Ring if node_count > 0 => vec![
+                (index + node_count - 1) % node_count,
+                (index + 1) % node_count,
+            ],
+            Topology::Ring => vec![],
+            Topology::Star if index == 0 => (0..node_count).collect(),
+            Topology::Star => vec![0],
+        };
+
+        peers.retain(|peer| *peer != index);
+        peers.sort_unstable();
+        peers.dedup();
+        peers
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ring_of_four() {
+        let peers = (0..4)
+            .map(|index| Topology::Ring.peers(index, 4))
+            .collect::<Vec<_>>();
+
+        assert_eq!(peers, vec![vec![1, 3], vec![0, 2], vec![1, 3], vec![0, 2]]);
+        assert!(peers.iter().all(|peers| peers.len() == 2));
+    }
+}
@@ -19,6 +19,7 @@ pub struct BlockServiceBuilder<T, E: EthSpec> {
     slot_clock: Option<Arc<T>>,
     beacon_node: Option<RemoteBeaconNode<E>>,
     context: Option<RuntimeContext<E>>,
+    graffiti: Option<[u8; 32]>,
 }
 
 impl<T: SlotClock + 'static, E: EthSpec> BlockServiceBuilder<T, E> {
@@ -29,6 +30,7 @@ impl<T: SlotClock + 'static, E: EthSpec> BlockServiceBuilder<T, E> {
             slot_clock: None,
             beacon_node: None,
             context: None,
+            graffiti: None,
         }
     }
 
@@ -57,6 +59,13 @@ impl<T: SlotClock + 'static, E: EthSpec> BlockServiceBuilder<T, E> {
         self
     }
 
+    /// Set the graffiti to be included in produced blocks. If `None`, the beacon node's default
+    /// graffiti is used.
+    pub fn graffiti(mut self, graffiti: Option<[u8; 32]>) -> Self {
+        self.graffiti = graffiti;
+        self
+    }
+
     pub fn build(self) -> Result<BlockService<T, E>, String> {
         Ok(BlockService {
             inner: Arc::new(Inner {
@@ -75,6 +84,7 @@ impl<T: SlotClock + 'static, E: EthSpec> BlockServiceBuilder<T, E> {
                 context: self
                     .context
                     .ok_or_else(|| "Cannot build BlockService without runtime_context")?,
+                graffiti: self.graffiti,
             }),
         })
     }
@@ -87,6 +97,7 @@ pub struct Inner<T, E: EthSpec> {
     slot_clock: Arc<T>,
     beacon_node: RemoteBeaconNode<E>,
     context: RuntimeContext<E>,
+    graffiti: Option<[u8; 32]>,
 }
 
 /// Attempts to produce attestations for any block producer(s) at the start of the epoch.
@@ -217,7 +228,7 @@ impl<T: SlotClock + 'static, E: EthSpec> BlockService<T, E> {
             .beacon_node
             .http
             .validator()
-            .produce_block(slot, randao_reveal)
+            .produce_block(slot, randao_reveal, self.graffiti)
             .await
             .map_err(|e| format!("Error from beacon node when producing block: {:?}", e))?;
 
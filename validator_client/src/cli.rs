@@ -11,8 +11,12 @@ pub fn cli_app<'a, 'b>() -> App<'a, 'b> {
         .arg(
             Arg::with_name("server")
                 .long("server")
-                .value_name("NETWORK_ADDRESS")
-                .help("Address to connect to BeaconNode.")
+                .visible_alias("servers")
+                .value_name("NETWORK_ADDRESSES")
+                .help(
+                    "Comma-separated addresses of beacon nodes. The first reachable node is \
+                    used until it fails, at which point the next node is used.",
+                )
                 .default_value(&DEFAULT_HTTP_SERVER)
                 .takes_value(true),
         )
@@ -32,16 +36,32 @@ pub fn cli_app<'a, 'b>() -> App<'a, 'b> {
                       node is not synced.",
                 ),
         )
+        .arg(
+            Arg::with_name("graffiti")
+                .long("graffiti")
+                .help("Specify your custom graffiti to be included in blocks (32 bytes maximum).")
+                .value_name("GRAFFITI")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("validators-file")
+                .long("validators-file")
+                .value_name("PATH")
+                .help(
+                    "Path to a file listing the validators to run, one per line, each either a \
+                    validator index or a 0x-prefixed public key. If present, the first and last \
+                    validator indices are ignored.",
+                )
+                .takes_value(true),
+        )
         .arg(
             Arg::with_name("first-validator")
                 .value_name("VALIDATOR_INDEX")
-                .required(true)
                 .help("The first validator public key to be generated for this client."),
         )
         .arg(
             Arg::with_name("last-validator")
                 .value_name("VALIDATOR_INDEX")
-                .required(true)
                 .help("The last validator public key to be generated for this client."),
         )
 }
@@ -1,22 +1,29 @@
 use clap::ArgMatches;
-use clap_utils::{parse_optional, parse_path_with_default_in_home_dir, parse_required};
+use clap_utils::{parse_optional, parse_path_with_default_in_home_dir};
 use serde_derive::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::fs;
+use std::path::{Path, PathBuf};
+use types::PublicKeyBytes;
 
 pub const DEFAULT_HTTP_SERVER: &str = "http://localhost:5052/";
 pub const DEFAULT_DATA_DIR: &str = ".lighthouse/validators";
 /// Path to the slashing protection database within the datadir.
 pub const SLASHING_PROTECTION_FILENAME: &str = "slashing_protection.sqlite";
+/// Path to the cache of known validator duties within the datadir.
+pub const DUTIES_CACHE_FILENAME: &str = "duties_cache.json";
+/// Path to the saved configuration within the datadir.
+pub const CONFIG_FILENAME: &str = "validator_config.toml";
 
 /// Stores the core configuration for this validator instance.
-#[derive(Clone, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct Config {
     /// The data directory, which stores all validator databases
     pub data_dir: PathBuf,
-    /// The http endpoint of the beacon node API.
+    /// The http endpoints of the beacon node APIs, in order of preference.
     ///
-    /// Should be similar to `http://localhost:8080`
-    pub http_server: String,
+    /// Each should be similar to `http://localhost:8080`
+    pub http_servers: Vec<String>,
     /// If true, the validator client will still poll for duties and produce blocks even if the
     /// beacon node is not synced at startup.
     pub allow_unsynced_beacon_node: bool,
@@ -25,6 +32,13 @@ pub struct Config {
 
     pub first_validator: usize,
     pub last_validator: usize,
+    /// If set, the graffiti to be included in every block produced by this client.
+    pub graffiti: Option<[u8; 32]>,
+    /// If set, the exact validator indices to run, taking precedence over `first_validator` and
+    /// `last_validator`.
+    pub validator_indices: Option<Vec<usize>>,
+    /// The public keys of any further validators to run, as listed in the validators file.
+    pub validator_pubkeys: Vec<PublicKeyBytes>,
 }
 
 impl Default for Config {
@@ -35,12 +49,15 @@ impl Default for Config {
             .unwrap_or_else(|| PathBuf::from("."));
         Self {
             data_dir,
-            http_server: DEFAULT_HTTP_SERVER.to_string(),
+            http_servers: vec![DEFAULT_HTTP_SERVER.to_string()],
             allow_unsynced_beacon_node: false,
             auto_register: false,
 
             first_validator: 0,
             last_validator: 0,
+            graffiti: None,
+            validator_indices: None,
+            validator_pubkeys: vec![],
         }
     }
 }
@@ -48,32 +65,315 @@ impl Default for Config {
 impl Config {
     /// Returns a `Default` implementation of `Self` with some parameters modified by the supplied
     /// `cli_args`.
+    ///
+    /// If a config file exists in the datadir, it is loaded first and the `cli_args` are applied
+    /// on top of it.
     pub fn from_cli(cli_args: &ArgMatches) -> Result<Config, String> {
-        let mut config = Config::default();
-
-        config.data_dir = parse_path_with_default_in_home_dir(
+        let data_dir = parse_path_with_default_in_home_dir(
             cli_args,
             "datadir",
             PathBuf::from(".lighthouse").join("validators"),
         )?;
 
-        if !config.data_dir.exists() {
+        if !data_dir.exists() {
             return Err(format!(
                 "The directory for validator data  (--datadir) does not exist: {:?}",
-                config.data_dir
+                data_dir
             ));
         }
 
-        if let Some(server) = parse_optional(cli_args, "server")? {
-            config.http_server = server;
+        let config_file = data_dir.join(CONFIG_FILENAME);
+        let mut config = if config_file.exists() {
+            Config::from_file(&config_file)?
+        } else {
+            Config::default()
+        };
+
+        config.data_dir = data_dir;
+
+        // The `server` flag has a default value, so only override the file if it was supplied.
+        if cli_args.occurrences_of("server") > 0 {
+            if let Some(servers) = parse_optional::<String>(cli_args, "server")? {
+                config.http_servers = parse_servers(&servers)?;
+            }
+        }
+
+        if cli_args.is_present("allow-unsynced") {
+            config.allow_unsynced_beacon_node = true;
+        }
+
+        if cli_args.is_present("auto-register") {
+            config.auto_register = true;
+        }
+
+        if let Some(graffiti) = parse_optional::<String>(cli_args, "graffiti")? {
+            config.graffiti = Some(parse_graffiti(&graffiti)?);
         }
 
-        config.allow_unsynced_beacon_node = cli_args.is_present("allow-unsynced");
-        config.auto_register = cli_args.is_present("auto-register");
+        // The validator range only replaces the one in the config file if it was supplied.
+        if let Some(path) = parse_optional::<PathBuf>(cli_args, "validators-file")? {
+            let (indices, pubkeys) = read_validators_file(&path)?;
+            config.validator_indices = Some(indices);
+            config.validator_pubkeys = pubkeys;
+        } else {
+            if let Some(first_validator) = parse_optional(cli_args, "first-validator")? {
+                config.first_validator = first_validator;
+                config.validator_indices = None;
+                config.validator_pubkeys = vec![];
+            }
 
-        config.first_validator = parse_required(cli_args, "first-validator")?;
-        config.last_validator = parse_required(cli_args, "last-validator")?;
+            if let Some(last_validator) = parse_optional(cli_args, "last-validator")? {
+                config.last_validator = last_validator;
+                config.validator_indices = None;
+                config.validator_pubkeys = vec![];
+            }
+        }
 
         Ok(config)
     }
+
+    /// Reads a `Config` from the TOML file at `path`. Fields missing from the file take their
+    /// default values.
+    pub fn from_file(path: &Path) -> Result<Config, String> {
+        let contents = fs::read_to_string(path)
+            .map_err(|e| format!("Unable to read config file {:?}: {:?}", path, e))?;
+
+        toml::from_str(&contents)
+            .map_err(|e| format!("Unable to parse config file {:?}: {:?}", path, e))
+    }
+
+    /// Writes `self` as TOML to the file at `path`, replacing any existing file.
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let contents =
+            toml::to_string(self).map_err(|e| format!("Unable to serialize config: {:?}", e))?;
+
+        fs::write(path, contents)
+            .map_err(|e| format!("Unable to write config file {:?}: {:?}", path, e))
+    }
+
+    /// Returns the indices of the validators to be run by this client.
+    pub fn validator_indices(&self) -> Vec<usize> {
+        match &self.validator_indices {
+            Some(indices) => indices.clone(),
+            None => (self.first_validator..=self.last_validator).collect(),
+        }
+    }
+}
+
+/// Parses a comma-separated list of beacon node addresses.
+fn parse_servers(servers: &str) -> Result<Vec<String>, String> {
+    let servers = servers
+        .split(',')
+        .map(str::trim)
+        .filter(|server| !server.is_empty())
+        .map(String::from)
+        .collect::<Vec<_>>();
+
+    if servers.is_empty() {
+        Err("At least one beacon node address must be provided".to_string())
+    } else {
+        Ok(servers)
+    }
+}
+
+/// Converts `graffiti` into 32 bytes, padding with zeros on the right.
+///
+/// Returns an error if `graffiti` is longer than 32 bytes.
+fn parse_graffiti(graffiti: &str) -> Result<[u8; 32], String> {
+    let bytes = graffiti.as_bytes();
+
+    if bytes.len() > 32 {
+        return Err(format!(
+            "Graffiti must be at most 32 bytes, got {} bytes",
+            bytes.len()
+        ));
+    }
+
+    let mut graffiti = [0; 32];
+    graffiti[..bytes.len()].copy_from_slice(bytes);
+
+    Ok(graffiti)
+}
+
+/// Reads a file containing one validator index or 0x-prefixed public key per line. Empty lines are
+/// ignored.
+fn read_validators_file(path: &Path) -> Result<(Vec<usize>, Vec<PublicKeyBytes>), String> {
+    let contents = fs::read_to_string(path)
+        .map_err(|e| format!("Unable to read validators file {:?}: {:?}", path, e))?;
+
+    parse_validators(&contents).map_err(|e| format!("Invalid validators file {:?}: {}", path, e))
+}
+
+/// Parses one validator index or 0x-prefixed public key per line, ignoring empty lines and
+/// duplicates.
+fn parse_validators(contents: &str) -> Result<(Vec<usize>, Vec<PublicKeyBytes>), String> {
+    let mut indices = vec![];
+    let mut pubkeys = vec![];
+
+    for (i, line) in contents.lines().enumerate() {
+        let line = line.trim();
+
+        if line.is_empty() {
+            continue;
+        }
+
+        if line.starts_with("0x") {
+            let pubkey = hex::decode(&line[2..])
+                .map_err(|e| format!("{:?}", e))
+                .and_then(|bytes| {
+                    PublicKeyBytes::from_bytes(&bytes).map_err(|e| format!("{:?}", e))
+                })
+                .map_err(|e| format!("line {} is not a validator public key: {}", i + 1, e))?;
+
+            if !pubkeys.contains(&pubkey) {
+                pubkeys.push(pubkey);
+            }
+        } else {
+            let index = line
+                .parse::<usize>()
+                .map_err(|e| format!("line {} is not a validator index: {:?}", i + 1, e))?;
+
+            if !indices.contains(&index) {
+                indices.push(index);
+            }
+        }
+    }
+
+    Ok((indices, pubkeys))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::cli_app;
+    use clap::Arg;
+    use tempdir::TempDir;
+    use types::Keypair;
+
+    /// Runs `Config::from_cli` on the validator client `args`, using `data_dir` as the datadir.
+    fn from_cli_args(data_dir: &Path, args: &[&str]) -> Result<Config, String> {
+        let data_dir = data_dir.to_str().expect("datadir should be valid utf-8");
+        let matches = cli_app()
+            // The datadir is a global flag of the `lighthouse` binary.
+            .arg(Arg::with_name("datadir").long("datadir").takes_value(true))
+            .get_matches_from_safe(
+                ["validator_client", "--datadir", data_dir]
+                    .iter()
+                    .chain(args.iter()),
+            )
+            .map_err(|e| format!("{:?}", e))?;
+
+        Config::from_cli(&matches)
+    }
+
+    #[test]
+    fn config_file_round_trip() {
+        let dir = TempDir::new("validator_config").expect("should create temp dir");
+        let path = dir.path().join(CONFIG_FILENAME);
+
+        let mut graffiti = [0; 32];
+        graffiti[..5].copy_from_slice(b"hello");
+
+        let config = Config {
+            data_dir: dir.path().to_path_buf(),
+            http_servers: vec!["http://a:5052/".to_string(), "http://b:5052/".to_string()],
+            allow_unsynced_beacon_node: true,
+            auto_register: true,
+            first_validator: 3,
+            last_validator: 7,
+            graffiti: Some(graffiti),
+            validator_indices: Some(vec![9, 2, 4]),
+            validator_pubkeys: vec![Keypair::random().pk.into()],
+        };
+
+        config.save(&path).expect("should save config");
+        assert_eq!(Config::from_file(&path), Ok(config));
+    }
+
+    #[test]
+    fn config_file_missing_fields() {
+        let dir = TempDir::new("validator_config").expect("should create temp dir");
+        let path = dir.path().join(CONFIG_FILENAME);
+
+        fs::write(&path, "auto_register = true\n").expect("should write config");
+
+        let config = Config::from_file(&path).expect("should load config");
+        assert!(config.auto_register);
+        assert_eq!(config.http_servers, Config::default().http_servers);
+    }
+
+    #[test]
+    fn cli_args_override_config_file() {
+        let dir = TempDir::new("validator_config").expect("should create temp dir");
+        let file_config = Config {
+            data_dir: dir.path().to_path_buf(),
+            http_servers: vec!["http://a:5052/".to_string()],
+            first_validator: 3,
+            last_validator: 7,
+            ..Config::default()
+        };
+        file_config
+            .save(&dir.path().join(CONFIG_FILENAME))
+            .expect("should save config");
+
+        // Values from the file survive when their flags are absent.
+        assert_eq!(from_cli_args(dir.path(), &[]), Ok(file_config.clone()));
+
+        let config = from_cli_args(dir.path(), &["--server", "http://b:5052/", "5", "9"])
+            .expect("should parse config");
+        assert_eq!(config.http_servers, vec!["http://b:5052/".to_string()]);
+        assert_eq!(config.first_validator, 5);
+        assert_eq!(config.last_validator, 9);
+    }
+
+    #[test]
+    fn parse_validators_file() {
+        assert_eq!(
+            parse_validators("3\n\n 7 \n1\n3\n"),
+            Ok((vec![3, 7, 1], vec![])),
+        );
+        assert!(parse_validators("3\n0x1234\n").is_err());
+        assert!(parse_validators("3\nabcd\n").is_err());
+    }
+
+    #[test]
+    fn parse_validators_file_with_pubkeys() {
+        let (a, b): (PublicKeyBytes, PublicKeyBytes) =
+            (Keypair::random().pk.into(), Keypair::random().pk.into());
+        let contents = format!(
+            "3\n0x{}\n7\n 0x{} \n0x{}\n3\n",
+            hex::encode(a.as_slice()),
+            hex::encode(b.as_slice()),
+            hex::encode(a.as_slice()),
+        );
+
+        assert_eq!(parse_validators(&contents), Ok((vec![3, 7], vec![a, b])));
+    }
+
+    #[test]
+    fn servers() {
+        assert_eq!(
+            parse_servers("http://a:5052/, http://b:5052/,http://c:5052/"),
+            Ok(vec![
+                "http://a:5052/".to_string(),
+                "http://b:5052/".to_string(),
+                "http://c:5052/".to_string(),
+            ])
+        );
+        assert_eq!(
+            parse_servers("http://a:5052/"),
+            Ok(vec!["http://a:5052/".to_string()])
+        );
+        assert!(parse_servers(" , ").is_err());
+    }
+
+    #[test]
+    fn graffiti() {
+        let mut expected = [0; 32];
+        expected[..5].copy_from_slice(b"hello");
+        assert_eq!(parse_graffiti("hello"), Ok(expected));
+
+        assert_eq!(parse_graffiti(&"a".repeat(32)), Ok([b'a'; 32]));
+        assert!(parse_graffiti(&"a".repeat(40)).is_err());
+    }
 }
@@ -1,21 +1,33 @@
-use crate::{is_synced::is_synced, validator_store::ValidatorStore};
+use crate::{is_synced::is_synced, subnet_manager::SubnetManager, validator_store::ValidatorStore};
 use environment::RuntimeContext;
 use futures::StreamExt;
 use parking_lot::RwLock;
-use remote_beacon_node::{PublishStatus, RemoteBeaconNode};
+use remote_beacon_node::{Error as BeaconNodeError, PublishStatus, RemoteBeaconNode};
 use rest_types::{ValidatorDuty, ValidatorDutyBytes, ValidatorSubscription};
-use slog::{debug, error, trace, warn};
+use slog::{debug, error, trace, warn, Logger};
 use slot_clock::SlotClock;
-use std::collections::HashMap;
+use std::collections::{hash_map::Entry, HashMap};
 use std::convert::TryInto;
+use std::fs;
 use std::ops::Deref;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::sync::Arc;
-use tokio::time::{interval_at, Duration, Instant};
-use types::{ChainSpec, CommitteeIndex, Epoch, EthSpec, PublicKey, SelectionProof, Slot};
+use tokio::time::{delay_for, interval_at, Duration, Instant};
+use types::{ChainSpec, CommitteeIndex, Epoch, EthSpec, PublicKey, SelectionProof, Slot, SubnetId};
 
 /// Delay this period of time after the slot starts. This allows the node to process the new slot.
 const TIME_DELAY_FROM_SLOT: Duration = Duration::from_millis(100);
 
+/// The number of times a duties request is attempted before giving up, if it keeps failing with
+/// transient errors.
+const DUTIES_REQUEST_ATTEMPTS: usize = 3;
+
+/// The delay before the first retry of a failed duties request. Each subsequent retry doubles it.
+///
+/// Kept short so that all attempts complete well within a single slot.
+const DUTIES_RETRY_BASE_DELAY: Duration = Duration::from_millis(100);
+
 /// Remove any duties where the `duties_epoch < current_epoch - PRUNE_DEPTH`.
 const PRUNE_DEPTH: u64 = 4;
 
@@ -157,9 +169,54 @@ impl InsertOutcome {
     }
 }
 
+/// A snapshot of the number of times each `InsertOutcome` has occurred whilst updating duties.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct DutiesMetrics {
+    pub new_validator: usize,
+    pub new_epoch: usize,
+    pub identical: usize,
+    pub replaced: usize,
+    pub invalid: usize,
+}
+
+/// Thread-safe counters of the outcomes of inserting duties into the `DutiesStore`.
+#[derive(Default)]
+struct OutcomeCounters {
+    new_validator: AtomicUsize,
+    new_epoch: AtomicUsize,
+    identical: AtomicUsize,
+    replaced: AtomicUsize,
+    invalid: AtomicUsize,
+}
+
+impl OutcomeCounters {
+    fn record(&self, outcome: &InsertOutcome) {
+        let counter = match outcome {
+            InsertOutcome::NewValidator => &self.new_validator,
+            InsertOutcome::NewEpoch => &self.new_epoch,
+            InsertOutcome::Identical => &self.identical,
+            InsertOutcome::Replaced { .. } => &self.replaced,
+            InsertOutcome::Invalid => &self.invalid,
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn snapshot(&self) -> DutiesMetrics {
+        DutiesMetrics {
+            new_validator: self.new_validator.load(Ordering::Relaxed),
+            new_epoch: self.new_epoch.load(Ordering::Relaxed),
+            identical: self.identical.load(Ordering::Relaxed),
+            replaced: self.replaced.load(Ordering::Relaxed),
+            invalid: self.invalid.load(Ordering::Relaxed),
+        }
+    }
+}
+
 #[derive(Default)]
 pub struct DutiesStore {
     store: RwLock<BaseHashMap>,
+    /// Set whenever the duties in `store` are added, replaced or removed.
+    changed: AtomicBool,
 }
 
 impl DutiesStore {
@@ -271,6 +328,7 @@ impl DutiesStore {
 
                     // Replace the existing duties.
                     *known_duties = duties;
+                    self.changed.store(true, Ordering::Relaxed);
 
                     Ok(InsertOutcome::Replaced { should_resubscribe })
                 }
@@ -279,6 +337,7 @@ impl DutiesStore {
                 duties.compute_selection_proof(validator_store)?;
 
                 validator_map.insert(epoch, duties);
+                self.changed.store(true, Ordering::Relaxed);
 
                 Ok(InsertOutcome::NewEpoch)
             }
@@ -292,18 +351,98 @@ impl DutiesStore {
             validator_map.insert(epoch, duties);
 
             store.insert(validator_pubkey, validator_map);
+            self.changed.store(true, Ordering::Relaxed);
 
             Ok(InsertOutcome::NewValidator)
         }
     }
 
+    /// Serializes the duties in the store (without selection proofs) into JSON bytes.
+    fn export(&self) -> Result<Vec<u8>, String> {
+        let duties = self
+            .store
+            .read()
+            .values()
+            .flat_map(|validator_map| {
+                validator_map
+                    .iter()
+                    .map(|(epoch, duties)| (*epoch, duties.duty.clone()))
+            })
+            .collect::<Vec<_>>();
+
+        serde_json::to_vec(&duties).map_err(|e| format!("Unable to serialize duties: {:?}", e))
+    }
+
+    /// Restores the duties in `bytes` (as produced by `Self::export`) into the store, returning
+    /// the number of duties that were added. The selection proof of each added duty is set with
+    /// `compute_selection_proof`.
+    ///
+    /// Duties for a `(validator, epoch)` that is already in the store are ignored, since the
+    /// duties in the store are at least as recent as any exported ones.
+    fn import<F>(
+        &self,
+        bytes: &[u8],
+        slots_per_epoch: u64,
+        mut compute_selection_proof: F,
+    ) -> Result<usize, String>
+    where
+        F: FnMut(&mut DutyAndProof) -> Result<(), String>,
+    {
+        let duties: Vec<(Epoch, ValidatorDuty)> = serde_json::from_slice(bytes)
+            .map_err(|e| format!("Unable to deserialize duties: {:?}", e))?;
+
+        let mut store = self.store.write();
+        let mut imported = 0;
+
+        for (epoch, duty) in duties {
+            if !duties_match_epoch(&duty, epoch, slots_per_epoch) {
+                continue;
+            }
+
+            let validator_map = store
+                .entry(duty.validator_pubkey.clone())
+                .or_insert_with(HashMap::new);
+
+            if let Entry::Vacant(entry) = validator_map.entry(epoch) {
+                let mut duties = DutyAndProof {
+                    duty,
+                    selection_proof: None,
+                };
+                compute_selection_proof(&mut duties)?;
+
+                entry.insert(duties);
+                imported += 1;
+            }
+        }
+
+        if imported > 0 {
+            self.changed.store(true, Ordering::Relaxed);
+        }
+
+        Ok(imported)
+    }
+
     fn prune(&self, prior_to: Epoch) {
+        let mut pruned = false;
+
         self.store
             .write()
             .retain(|_validator_pubkey, validator_map| {
+                let len = validator_map.len();
                 validator_map.retain(|epoch, _duties| *epoch >= prior_to);
+                pruned |= validator_map.len() != len;
                 !validator_map.is_empty()
             });
+
+        if pruned {
+            self.changed.store(true, Ordering::Relaxed);
+        }
+    }
+
+    /// Returns `true` if the duties in the store have changed since this function was last
+    /// called.
+    fn take_changed(&self) -> bool {
+        self.changed.swap(false, Ordering::Relaxed)
     }
 }
 
@@ -313,6 +452,7 @@ pub struct DutiesServiceBuilder<T, E: EthSpec> {
     beacon_node: Option<RemoteBeaconNode<E>>,
     context: Option<RuntimeContext<E>>,
     allow_unsynced_beacon_node: bool,
+    cache_path: Option<PathBuf>,
 }
 
 impl<T: SlotClock + 'static, E: EthSpec> DutiesServiceBuilder<T, E> {
@@ -323,6 +463,7 @@ impl<T: SlotClock + 'static, E: EthSpec> DutiesServiceBuilder<T, E> {
             beacon_node: None,
             context: None,
             allow_unsynced_beacon_node: false,
+            cache_path: None,
         }
     }
 
@@ -352,10 +493,18 @@ impl<T: SlotClock + 'static, E: EthSpec> DutiesServiceBuilder<T, E> {
         self
     }
 
+    /// If set, the known duties are written to the file at `path` after each update.
+    pub fn cache_path(mut self, path: PathBuf) -> Self {
+        self.cache_path = Some(path);
+        self
+    }
+
     pub fn build(self) -> Result<DutiesService<T, E>, String> {
         Ok(DutiesService {
             inner: Arc::new(Inner {
                 store: Arc::new(DutiesStore::default()),
+                outcome_counters: OutcomeCounters::default(),
+                subnet_manager: SubnetManager::default(),
                 validator_store: self
                     .validator_store
                     .ok_or_else(|| "Cannot build DutiesService without validator_store")?,
@@ -369,6 +518,7 @@ impl<T: SlotClock + 'static, E: EthSpec> DutiesServiceBuilder<T, E> {
                     .context
                     .ok_or_else(|| "Cannot build DutiesService without runtime_context")?,
                 allow_unsynced_beacon_node: self.allow_unsynced_beacon_node,
+                cache_path: self.cache_path,
             }),
         })
     }
@@ -377,6 +527,10 @@ impl<T: SlotClock + 'static, E: EthSpec> DutiesServiceBuilder<T, E> {
 /// Helper to minimise `Arc` usage.
 pub struct Inner<T, E: EthSpec> {
     store: Arc<DutiesStore>,
+    /// Counts the outcomes of all duties updates since the service was started.
+    outcome_counters: OutcomeCounters,
+    /// The attestation subnets required by the known duties.
+    subnet_manager: SubnetManager,
     validator_store: ValidatorStore<T, E>,
     pub(crate) slot_clock: T,
     pub(crate) beacon_node: RemoteBeaconNode<E>,
@@ -384,6 +538,8 @@ pub struct Inner<T, E: EthSpec> {
     /// If true, the duties service will poll for duties from the beacon node even if it is not
     /// synced.
     allow_unsynced_beacon_node: bool,
+    /// If set, the file to which the known duties are written after each update.
+    cache_path: Option<PathBuf>,
 }
 
 /// Maintains a store of the duties for all voting validators in the `validator_store`.
@@ -439,6 +595,32 @@ impl<T: SlotClock + 'static, E: EthSpec> DutiesService<T, E> {
         self.store.attesters(slot, E::slots_per_epoch())
     }
 
+    /// Returns the attestation subnets required by the duties of the managed validators in the
+    /// given epoch.
+    pub fn subnets(&self, epoch: Epoch) -> Vec<SubnetId> {
+        self.subnet_manager.subnets(epoch)
+    }
+
+    /// Returns the number of times each outcome has occurred when updating duties from the beacon
+    /// node, since the service was started.
+    pub fn metrics(&self) -> DutiesMetrics {
+        self.outcome_counters.snapshot()
+    }
+
+    /// Returns all known duties serialized as JSON bytes, suitable for restoring with
+    /// `Self::import_duties`.
+    pub fn export_duties(&self) -> Result<Vec<u8>, String> {
+        self.store.export()
+    }
+
+    /// Restores duties previously produced by `Self::export_duties`, returning the number of
+    /// duties that were added. Duties already known to the service are never replaced.
+    pub fn import_duties(&self, bytes: &[u8]) -> Result<usize, String> {
+        self.store.import(bytes, E::slots_per_epoch(), |duties| {
+            duties.compute_selection_proof(&self.validator_store)
+        })
+    }
+
     /// Start the service that periodically polls the beacon node for validator duties.
     pub fn start_update_service(self, spec: &ChainSpec) -> Result<(), String> {
         let duration_to_next_slot = self
@@ -505,12 +687,18 @@ impl<T: SlotClock + 'static, E: EthSpec> DutiesService<T, E> {
                     );
 
                     self.store.prune(prune_below);
+                    self.subnet_manager.prune(prune_below);
                 }
 
                 epoch
             })?;
 
-        let result = self.clone().update_epoch(current_epoch).await;
+        // The current epoch's duties are needed immediately, so retry transient failures. The next
+        // epoch's duties are requested again on every slot, so a single attempt is enough.
+        let result = self
+            .clone()
+            .update_epoch_with_backoff(current_epoch, DUTIES_REQUEST_ATTEMPTS)
+            .await;
         if let Err(e) = result {
             error!(
                 log,
@@ -530,6 +718,35 @@ impl<T: SlotClock + 'static, E: EthSpec> DutiesService<T, E> {
                 );
             })?;
 
+        if let Some(path) = self.cache_path.clone() {
+            if !self.store.take_changed() {
+                return Ok(());
+            }
+
+            let service = self.clone();
+            let log = log.clone();
+
+            self.context.executor.spawn_blocking(
+                move || {
+                    if let Err(e) = service
+                        .export_duties()
+                        .and_then(|bytes| write_cache_file(&path, &bytes))
+                    {
+                        // Try again after the next update.
+                        service.store.changed.store(true, Ordering::Relaxed);
+
+                        warn!(
+                            log,
+                            "Failed to write duties cache";
+                            "error" => e,
+                            "path" => format!("{:?}", path)
+                        );
+                    }
+                },
+                "duties_cache_write",
+            );
+        }
+
         Ok(())
     }
 
@@ -544,7 +761,48 @@ impl<T: SlotClock + 'static, E: EthSpec> DutiesService<T, E> {
             .await
             .map_err(move |e| format!("Failed to get duties for epoch {}: {:?}", epoch, e))?;
 
+        self.import_epoch_duties(epoch, all_duties).await
+    }
+
+    /// As `update_epoch`, but makes up to `attempts` requests, retrying transient errors with an
+    /// exponentially increasing delay.
+    async fn update_epoch_with_backoff(self, epoch: Epoch, attempts: usize) -> Result<(), String> {
+        let pubkeys = self.validator_store.voting_pubkeys();
+        let all_duties = get_duties_with_backoff(
+            &self.beacon_node,
+            self.context.log(),
+            epoch,
+            pubkeys.as_slice(),
+            DUTIES_RETRY_BASE_DELAY,
+            attempts,
+        )
+        .await
+        .map_err(move |e| format!("Failed to get duties for epoch {}: {:?}", epoch, e))?;
+
+        self.import_epoch_duties(epoch, all_duties).await
+    }
+
+    /// Store the duties downloaded for `epoch`, subscribing to any new subnets they require.
+    async fn import_epoch_duties(
+        self,
+        epoch: Epoch,
+        all_duties: Vec<ValidatorDutyBytes>,
+    ) -> Result<(), String> {
         let log = self.context.log().clone();
+        let spec = &self.context.eth2_config.spec;
+
+        // Any duty on a subnet that was not previously required must be subscribed, even if the
+        // duty itself is already known (e.g., it was restored from the duties cache).
+        let new_subnets = self.subnet_manager.update::<E>(epoch, &all_duties, spec);
+
+        if !new_subnets.is_empty() {
+            debug!(
+                log,
+                "New attestation subnets required";
+                "subnets" => format!("{:?}", new_subnets.iter().map(|subnet_id| **subnet_id).collect::<Vec<_>>()),
+                "epoch" => format!("{}", epoch)
+            );
+        }
 
         let mut new_validator = 0;
         let mut new_epoch = 0;
@@ -585,6 +843,8 @@ impl<T: SlotClock + 'static, E: EthSpec> DutiesService<T, E> {
                     })
                     .ok()?;
 
+                self.outcome_counters.record(&outcome);
+
                 match &outcome {
                     InsertOutcome::NewValidator => {
                         debug!(
@@ -606,7 +866,10 @@ impl<T: SlotClock + 'static, E: EthSpec> DutiesService<T, E> {
                 // with the store that the validator is an aggregator.
                 let is_aggregator = self.store.is_aggregator(&validator_pubkey, epoch)?;
 
-                if outcome.is_subscription_candidate() {
+                let requires_new_subnet = SubnetManager::subnet_for_duty::<E>(&remote_duties, spec)
+                    .map_or(false, |subnet_id| new_subnets.contains(&subnet_id));
+
+                if outcome.is_subscription_candidate() || requires_new_subnet {
                     Some(ValidatorSubscription {
                         validator_index: remote_duties.validator_index?,
                         attestation_committee_index: remote_duties.attestation_committee_index?,
@@ -693,3 +956,290 @@ fn duties_match_epoch(duties: &ValidatorDuty, epoch: Epoch, slots_per_epoch: u64
             .iter()
             .all(|slot| slot.epoch(slots_per_epoch) == epoch)
 }
+
+/// Request the duties of `pubkeys` in `epoch` from `beacon_node`, making up to `attempts` requests.
+///
+/// Transient errors (e.g., timeouts or server errors) are retried after the delays given by
+/// `retry_delays`, whilst any other error is returned immediately.
+async fn get_duties_with_backoff<E: EthSpec>(
+    beacon_node: &RemoteBeaconNode<E>,
+    log: &Logger,
+    epoch: Epoch,
+    pubkeys: &[PublicKey],
+    base_delay: Duration,
+    attempts: usize,
+) -> Result<Vec<ValidatorDutyBytes>, BeaconNodeError> {
+    let mut delays = retry_delays(base_delay, attempts);
+    let mut attempt = 1;
+
+    loop {
+        match beacon_node
+            .http
+            .validator()
+            .get_duties(epoch, pubkeys)
+            .await
+        {
+            Err(e) if e.is_transient() => match delays.next() {
+                Some(delay) => {
+                    debug!(
+                        log,
+                        "Retrying duties request";
+                        "error" => format!("{:?}", e),
+                        "delay" => format!("{:?}", delay),
+                        "attempt" => attempt,
+                        "epoch" => format!("{}", epoch),
+                    );
+
+                    delay_for(delay).await;
+                    attempt += 1;
+                }
+                None => return Err(e),
+            },
+            result => return result,
+        }
+    }
+}
+
+/// Writes `bytes` to a temporary file beside `path` and then renames it to `path`, so that the file
+/// at `path` is never left partially written.
+fn write_cache_file(path: &Path, bytes: &[u8]) -> Result<(), String> {
+    let temp_path = path.with_extension("tmp");
+
+    fs::write(&temp_path, bytes)
+        .and_then(|()| fs::rename(&temp_path, path))
+        .map_err(|e| format!("{:?}", e))
+}
+
+/// Returns the delays to wait before each retry of a request that is made up to `attempts` times,
+/// starting at `base` and doubling with each retry.
+fn retry_delays(base: Duration, attempts: usize) -> impl Iterator<Item = Duration> {
+    std::iter::successors(Some(base), |delay| delay.checked_mul(2)).take(attempts.saturating_sub(1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+    use types::{Keypair, MinimalEthSpec};
+
+    type E = MinimalEthSpec;
+
+    fn duty(validator_pubkey: PublicKey, attestation_slot: Slot) -> DutyAndProof {
+        DutyAndProof {
+            duty: ValidatorDuty {
+                validator_pubkey,
+                validator_index: Some(0),
+                attestation_slot: Some(attestation_slot),
+                attestation_committee_index: Some(0),
+                attestation_committee_position: Some(0),
+                committee_count_at_slot: Some(1),
+                block_proposal_slots: vec![],
+                aggregator_modulo: None,
+            },
+            selection_proof: None,
+        }
+    }
+
+    /// Adds `duties` directly to the `store`, without marking it as changed.
+    fn put(store: &DutiesStore, epoch: Epoch, duties: DutyAndProof) {
+        store
+            .store
+            .write()
+            .entry(duties.duty.validator_pubkey.clone())
+            .or_insert_with(HashMap::new)
+            .insert(epoch, duties);
+    }
+
+    #[test]
+    fn export_import_round_trip() {
+        let slots_per_epoch = E::slots_per_epoch();
+        let epoch = Epoch::new(2);
+        let slot = epoch.start_slot(slots_per_epoch);
+        let (a, b) = (Keypair::random().pk, Keypair::random().pk);
+
+        let exported = DutiesStore::default();
+        put(&exported, epoch, duty(a.clone(), slot));
+        put(&exported, epoch, duty(b.clone(), slot));
+        // Duties from the wrong epoch are never imported.
+        put(&exported, epoch + 1, duty(b.clone(), slot));
+        let bytes = exported.export().unwrap();
+
+        // The newer duties already in the store win over the exported ones.
+        let store = DutiesStore::default();
+        put(&store, epoch, duty(a.clone(), slot + 1));
+
+        assert_eq!(
+            store.import(&bytes, slots_per_epoch, |_| Ok(())).unwrap(),
+            1
+        );
+
+        let duties = store.store.read();
+        assert_eq!(duties[&a][&epoch].duty.attestation_slot, Some(slot + 1));
+        assert_eq!(duties[&b][&epoch].duty.attestation_slot, Some(slot));
+        assert!(!duties[&b].contains_key(&(epoch + 1)));
+    }
+
+    #[test]
+    fn tracks_changes() {
+        let slots_per_epoch = E::slots_per_epoch();
+        let epoch = Epoch::new(2);
+        let slot = epoch.start_slot(slots_per_epoch);
+
+        let exported = DutiesStore::default();
+        put(&exported, epoch, duty(Keypair::random().pk, slot));
+        let bytes = exported.export().unwrap();
+
+        let store = DutiesStore::default();
+        assert!(!store.take_changed());
+
+        assert_eq!(
+            store.import(&bytes, slots_per_epoch, |_| Ok(())).unwrap(),
+            1
+        );
+        assert!(store.take_changed());
+        assert!(!store.take_changed());
+
+        // Importing the same duties again adds nothing.
+        assert_eq!(
+            store.import(&bytes, slots_per_epoch, |_| Ok(())).unwrap(),
+            0
+        );
+        assert!(!store.take_changed());
+
+        store.prune(epoch);
+        assert!(!store.take_changed());
+
+        store.prune(epoch + 1);
+        assert!(store.take_changed());
+    }
+
+    #[test]
+    fn cache_file_replaced_atomically() {
+        let dir = tempdir::TempDir::new("duties_cache").expect("should create temp dir");
+        let path = dir.path().join("duties_cache.json");
+
+        write_cache_file(&path, b"first").expect("should write cache");
+        write_cache_file(&path, b"second").expect("should replace cache");
+
+        assert_eq!(fs::read(&path).expect("should read cache"), b"second");
+        assert!(!path.with_extension("tmp").exists());
+    }
+
+    #[test]
+    fn duties_retry_schedule() {
+        assert_eq!(
+            retry_delays(DUTIES_RETRY_BASE_DELAY, DUTIES_REQUEST_ATTEMPTS).collect::<Vec<_>>(),
+            vec![Duration::from_millis(100), Duration::from_millis(200)]
+        );
+        assert_eq!(retry_delays(DUTIES_RETRY_BASE_DELAY, 1).count(), 0);
+        assert_eq!(retry_delays(DUTIES_RETRY_BASE_DELAY, 0).count(), 0);
+    }
+
+    #[test]
+    fn outcome_counters_snapshot() {
+        let counters = OutcomeCounters::default();
+        assert_eq!(counters.snapshot(), DutiesMetrics::default());
+
+        for outcome in &[
+            InsertOutcome::NewValidator,
+            InsertOutcome::NewEpoch,
+            InsertOutcome::NewEpoch,
+            InsertOutcome::Identical,
+            InsertOutcome::Identical,
+            InsertOutcome::Identical,
+            InsertOutcome::Replaced {
+                should_resubscribe: true,
+            },
+            InsertOutcome::Replaced {
+                should_resubscribe: false,
+            },
+            InsertOutcome::Invalid,
+        ] {
+            counters.record(outcome);
+        }
+
+        assert_eq!(
+            counters.snapshot(),
+            DutiesMetrics {
+                new_validator: 1,
+                new_epoch: 2,
+                identical: 3,
+                replaced: 2,
+                invalid: 1,
+            }
+        );
+    }
+
+    /// Starts a beacon node that answers the `n`th request with the `n`th of `responses` (or the
+    /// last one, once they run out), returning its URL and the number of requests it has received.
+    async fn mock_node(
+        responses: &'static [(&'static str, &'static str)],
+    ) -> (String, Arc<AtomicUsize>) {
+        let mut listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/", listener.local_addr().unwrap());
+        let requests = Arc::new(AtomicUsize::new(0));
+        let counter = requests.clone();
+
+        tokio::spawn(async move {
+            while let Ok((mut socket, _)) = listener.accept().await {
+                let i = counter.fetch_add(1, Ordering::Relaxed);
+                let (status, body) = responses[i.min(responses.len() - 1)];
+
+                let mut buf = [0; 4096];
+                let _ = socket.read(&mut buf).await;
+                let response = format!(
+                    "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                );
+                let _ = socket.write_all(response.as_bytes()).await;
+            }
+        });
+
+        (url, requests)
+    }
+
+    /// Requests duties from the node at `url`, making up to `DUTIES_REQUEST_ATTEMPTS` requests.
+    async fn get_duties_from(url: String) -> Result<Vec<ValidatorDutyBytes>, BeaconNodeError> {
+        let beacon_node =
+            RemoteBeaconNode::<E>::new_with_timeout(url, Duration::from_secs(5)).unwrap();
+        let log = Logger::root(slog::Discard, slog::o!());
+
+        get_duties_with_backoff(
+            &beacon_node,
+            &log,
+            Epoch::new(0),
+            &[],
+            Duration::from_millis(1),
+            DUTIES_REQUEST_ATTEMPTS,
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn retries_transient_duties_errors() {
+        let (url, requests) =
+            mock_node(&[("500 Internal Server Error", ""), ("200 OK", "[]")]).await;
+
+        assert!(get_duties_from(url).await.unwrap().is_empty());
+        assert_eq!(requests.load(Ordering::Relaxed), 2);
+    }
+
+    #[tokio::test]
+    async fn does_not_retry_permanent_duties_errors() {
+        let (url, requests) = mock_node(&[("400 Bad Request", ""), ("200 OK", "[]")]).await;
+
+        assert!(get_duties_from(url).await.is_err());
+        assert_eq!(requests.load(Ordering::Relaxed), 1);
+    }
+
+    #[tokio::test]
+    async fn gives_up_after_all_duties_attempts() {
+        let (url, requests) = mock_node(&[("503 Service Unavailable", "")]).await;
+
+        assert!(get_duties_from(url).await.is_err());
+        assert_eq!(requests.load(Ordering::Relaxed), DUTIES_REQUEST_ATTEMPTS);
+    }
+}
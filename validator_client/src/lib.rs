@@ -6,6 +6,7 @@ mod duties_service;
 mod fork_service;
 mod is_synced;
 mod notifier;
+mod subnet_manager;
 mod validator_store;
 
 pub use cli::cli_app;
@@ -14,7 +15,7 @@ pub use config::Config;
 use attestation_service::{AttestationService, AttestationServiceBuilder};
 use block_service::{BlockService, BlockServiceBuilder};
 use clap::ArgMatches;
-use config::SLASHING_PROTECTION_FILENAME;
+use config::{DUTIES_CACHE_FILENAME, SLASHING_PROTECTION_FILENAME};
 use duties_service::{DutiesService, DutiesServiceBuilder};
 use environment::RuntimeContext;
 use fork_service::{ForkService, ForkServiceBuilder};
@@ -23,9 +24,13 @@ use remote_beacon_node::RemoteBeaconNode;
 use slog::{error, info, warn, Logger};
 use slot_clock::SlotClock;
 use slot_clock::SystemTimeSlotClock;
+use std::convert::TryInto;
+use std::fs;
 use std::time::{SystemTime, UNIX_EPOCH};
 use tokio::time::{delay_for, Duration};
-use types::{test_utils::generate_deterministic_keypair, EthSpec};
+use types::{
+    test_utils::generate_deterministic_keypair, EthSpec, Keypair, PublicKey, PublicKeyBytes,
+};
 use validator_store::ValidatorStore;
 
 /// The interval between attempts to contact the beacon node during startup.
@@ -63,7 +68,7 @@ impl<T: EthSpec> ProductionValidatorClient<T> {
         info!(
             log,
             "Starting validator client";
-            "beacon_node" => &config.http_server,
+            "beacon_nodes" => format!("{:?}", &config.http_servers),
             "datadir" => format!("{:?}", config.data_dir),
         );
 
@@ -75,7 +80,9 @@ impl<T: EthSpec> ProductionValidatorClient<T> {
             );
         }
 
-        let validators = (config.first_validator..=config.last_validator)
+        let mut validators = config
+            .validator_indices()
+            .into_iter()
             .map(generate_deterministic_keypair)
             .collect::<Vec<_>>();
 
@@ -86,7 +93,7 @@ impl<T: EthSpec> ProductionValidatorClient<T> {
         );
 
         let beacon_node =
-            RemoteBeaconNode::new_with_timeout(config.http_server.clone(), HTTP_TIMEOUT)
+            RemoteBeaconNode::new_with_fallbacks(config.http_servers.clone(), HTTP_TIMEOUT)
                 .map_err(|e| format!("Unable to init beacon node http client: {}", e))?;
 
         // TODO: check if all logs in wait_for_node are produed while awaiting
@@ -157,6 +164,18 @@ impl<T: EthSpec> ProductionValidatorClient<T> {
         // for Lighthouse.
         context.eth2_config = eth2_config;
 
+        if !config.validator_pubkeys.is_empty() {
+            let keypairs = keypairs_for_pubkeys(&beacon_node, &config.validator_pubkeys).await?;
+
+            info!(
+                log,
+                "Resolved validator public keys";
+                "count" => keypairs.len(),
+            );
+
+            validators.extend(keypairs);
+        }
+
         let slot_clock = SystemTimeSlotClock::new(
             context.eth2_config.spec.genesis_slot,
             Duration::from_secs(genesis_time),
@@ -190,20 +209,41 @@ impl<T: EthSpec> ProductionValidatorClient<T> {
             info!(log, "Validator auto-registration complete");
         }
 
+        let duties_cache_path = config.data_dir.join(DUTIES_CACHE_FILENAME);
+
         let duties_service = DutiesServiceBuilder::new()
             .slot_clock(slot_clock.clone())
             .validator_store(validator_store.clone())
             .beacon_node(beacon_node.clone())
             .runtime_context(context.service_context("duties".into()))
             .allow_unsynced_beacon_node(config.allow_unsynced_beacon_node)
+            .cache_path(duties_cache_path.clone())
             .build()?;
 
+        // Restore any duties known prior to a restart, so that they are available before the
+        // first update from the beacon node.
+        if duties_cache_path.exists() {
+            match fs::read(&duties_cache_path)
+                .map_err(|e| format!("{:?}", e))
+                .and_then(|bytes| duties_service.import_duties(&bytes))
+            {
+                Ok(count) => info!(log, "Restored cached duties"; "count" => count),
+                Err(e) => warn!(
+                    log,
+                    "Unable to restore cached duties";
+                    "error" => e,
+                    "path" => format!("{:?}", duties_cache_path)
+                ),
+            }
+        }
+
         let block_service = BlockServiceBuilder::new()
             .duties_service(duties_service.clone())
             .slot_clock(slot_clock.clone())
             .validator_store(validator_store.clone())
             .beacon_node(beacon_node.clone())
             .runtime_context(context.service_context("block".into()))
+            .graffiti(config.graffiti)
             .build()?;
 
         let attestation_service = AttestationServiceBuilder::new()
@@ -274,6 +314,7 @@ async fn wait_for_node<E: EthSpec>(
                     log,
                     "Connected to beacon node";
                     "version" => version,
+                    "endpoint" => beacon_node.http.current_url().as_str(),
                 );
 
                 return Ok(beacon_node);
@@ -289,3 +330,45 @@ async fn wait_for_node<E: EthSpec>(
         }
     }
 }
+
+/// Returns the keypairs of the validators with the given `pubkeys`.
+///
+/// Keypairs are derived from validator indices, so the index of each public key is looked up on
+/// the beacon node. Returns an error if a validator is unknown or its key cannot be derived.
+async fn keypairs_for_pubkeys<E: EthSpec>(
+    beacon_node: &RemoteBeaconNode<E>,
+    pubkeys: &[PublicKeyBytes],
+) -> Result<Vec<Keypair>, String> {
+    let pubkeys = pubkeys
+        .iter()
+        .map(|pubkey| {
+            pubkey
+                .try_into()
+                .map_err(|e| format!("Invalid validator public key {:?}: {:?}", pubkey, e))
+        })
+        .collect::<Result<Vec<PublicKey>, _>>()?;
+
+    beacon_node
+        .http
+        .beacon()
+        .get_validators(pubkeys, None)
+        .await
+        .map_err(|e| format!("Unable to read validators from beacon node: {:?}", e))?
+        .into_iter()
+        .map(|response| {
+            let validator_index = response
+                .validator_index
+                .ok_or_else(|| format!("Unknown validator {:?}", response.pubkey))?;
+            let keypair = generate_deterministic_keypair(validator_index);
+
+            if PublicKeyBytes::from(keypair.pk.clone()) == response.pubkey {
+                Ok(keypair)
+            } else {
+                Err(format!(
+                    "No signing key for validator {} ({:?})",
+                    validator_index, response.pubkey
+                ))
+            }
+        })
+        .collect()
+}
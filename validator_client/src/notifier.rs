@@ -1,6 +1,6 @@
 use crate::{is_synced::is_synced, ProductionValidatorClient};
 use futures::StreamExt;
-use slog::{error, info};
+use slog::{debug, error, info};
 use slot_clock::SlotClock;
 use tokio::time::{interval_at, Duration, Instant};
 use types::EthSpec;
@@ -40,6 +40,25 @@ pub fn spawn_notifier<T: EthSpec>(client: &ProductionValidatorClient<T>) -> Resu
             if let Some(slot) = duties_service.slot_clock.now() {
                 let epoch = slot.epoch(T::slots_per_epoch());
 
+                if slot % T::slots_per_epoch() == 0 {
+                    let metrics = duties_service.metrics();
+                    debug!(
+                        log,
+                        "Duties update totals";
+                        "new_validator" => metrics.new_validator,
+                        "new_epoch" => metrics.new_epoch,
+                        "identical" => metrics.identical,
+                        "replaced" => metrics.replaced,
+                        "invalid" => metrics.invalid,
+                    );
+                    debug!(
+                        log,
+                        "Attestation subnets";
+                        "subnets" => format!("{:?}", duties_service.subnets(epoch).iter().map(|subnet_id| **subnet_id).collect::<Vec<_>>()),
+                        "epoch" => format!("{}", epoch),
+                    );
+                }
+
                 let total_validators = duties_service.total_validator_count();
                 let proposing_validators = duties_service.proposer_count(epoch);
                 let attesting_validators = duties_service.attester_count(epoch);
@@ -0,0 +1,116 @@
+use parking_lot::RwLock;
+use rest_types::ValidatorDuty;
+use std::collections::{HashMap, HashSet};
+use types::{ChainSpec, Epoch, EthSpec, SubnetId};
+
+/// Tracks the attestation subnets required by the duties of the local validators in each epoch.
+///
+/// The beacon node subscribes to a subnet when it receives a subscription for a duty on that
+/// subnet, so the duties service uses this to determine when a subscription must be (re)sent.
+#[derive(Default)]
+pub struct SubnetManager {
+    subnets: RwLock<HashMap<Epoch, HashSet<SubnetId>>>,
+}
+
+impl SubnetManager {
+    /// Returns the subnet on which the attestation for `duty` should be published, if the duty
+    /// contains an attestation assignment.
+    pub fn subnet_for_duty<E: EthSpec>(duty: &ValidatorDuty, spec: &ChainSpec) -> Option<SubnetId> {
+        SubnetId::compute_subnet::<E>(
+            duty.attestation_slot?,
+            duty.attestation_committee_index?,
+            duty.committee_count_at_slot?,
+            spec,
+        )
+        .ok()
+    }
+
+    /// Replaces the subnets required in `epoch` with those required by the attestation
+    /// assignments in `duties`. Assignments for any other epoch are ignored.
+    ///
+    /// Returns the subnets which were not required in `epoch` prior to this call.
+    pub fn update<E: EthSpec>(
+        &self,
+        epoch: Epoch,
+        duties: &[ValidatorDuty],
+        spec: &ChainSpec,
+    ) -> Vec<SubnetId> {
+        let required = duties
+            .iter()
+            .filter(|duty| {
+                duty.attestation_slot
+                    .map_or(false, |slot| slot.epoch(E::slots_per_epoch()) == epoch)
+            })
+            .filter_map(|duty| Self::subnet_for_duty::<E>(duty, spec))
+            .collect::<HashSet<_>>();
+
+        let mut subnets = self.subnets.write();
+        let previous = subnets.insert(epoch, required.clone()).unwrap_or_default();
+
+        let mut new_subnets = required.difference(&previous).copied().collect::<Vec<_>>();
+        new_subnets.sort_by_key(|subnet_id| **subnet_id);
+        new_subnets
+    }
+
+    /// Returns the subnets required in `epoch`, in ascending order.
+    pub fn subnets(&self, epoch: Epoch) -> Vec<SubnetId> {
+        let mut subnets = self
+            .subnets
+            .read()
+            .get(&epoch)
+            .map(|subnets| subnets.iter().copied().collect::<Vec<_>>())
+            .unwrap_or_default();
+        subnets.sort_by_key(|subnet_id| **subnet_id);
+        subnets
+    }
+
+    /// Forgets the subnets required in any epoch prior to `prior_to`.
+    pub fn prune(&self, prior_to: Epoch) {
+        self.subnets.write().retain(|epoch, _| *epoch >= prior_to);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use types::{Keypair, MinimalEthSpec, Slot};
+
+    type E = MinimalEthSpec;
+
+    fn duty(slot: u64, committee_index: u64, committee_count_at_slot: u64) -> ValidatorDuty {
+        ValidatorDuty {
+            validator_pubkey: Keypair::random().pk,
+            validator_index: Some(0),
+            attestation_slot: Some(Slot::new(slot)),
+            attestation_committee_index: Some(committee_index),
+            attestation_committee_position: Some(0),
+            committee_count_at_slot: Some(committee_count_at_slot),
+            block_proposal_slots: vec![],
+            aggregator_modulo: Some(1),
+        }
+    }
+
+    #[test]
+    fn subscribes_to_assigned_subnet() {
+        let spec = E::default_spec();
+        let manager = SubnetManager::default();
+        let epoch = Epoch::new(2);
+
+        // The fourth slot of epoch 2, with 4 committees per slot. The assignment is to the
+        // second committee of that slot, which is committee `4 * 3 + 1 = 13` of the epoch.
+        let slot = epoch.start_slot(E::slots_per_epoch()).as_u64() + 3;
+        let duties = vec![duty(slot, 1, 4), duty(slot + E::slots_per_epoch(), 0, 4)];
+
+        assert_eq!(
+            manager.update::<E>(epoch, &duties, &spec),
+            vec![SubnetId::new(13)]
+        );
+        assert_eq!(manager.subnets(epoch), vec![SubnetId::new(13)]);
+
+        // The subnet is already known, so no new subscriptions are required.
+        assert!(manager.update::<E>(epoch, &duties, &spec).is_empty());
+
+        manager.prune(epoch + 1);
+        assert!(manager.subnets(epoch).is_empty());
+    }
+}